@@ -0,0 +1,240 @@
+// Project name: TorusSQL.
+// Description: Relational database management system.
+// Licence: GPL-3.0.
+// Author: Alexander (@alkuzin).
+
+//! TorusSQL errors related declarations.
+
+use std::fmt::{Display, Formatter};
+
+/// TorusSQL result type alias.
+pub type Result<T> = std::result::Result<T, TorusError>;
+
+/// TorusSQL error kinds enumeration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// SQL code contains invalid sequence of characters.
+    Lexical,
+    /// SQL code does not match SQL grammar.
+    Syntax,
+    /// SQL statement is grammatically correct, but meaningless.
+    Semantic,
+    /// SQL statement is not supported yet.
+    Unsupported,
+}
+
+impl Display for ErrorKind {
+    /// Display TorusSQL error kind.
+    ///
+    /// # Parameters
+    /// - `f` - given formatter.
+    ///
+    /// # Returns
+    /// - `OK`  - in case of success.
+    /// - `Err` - otherwise.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let result = match self {
+            ErrorKind::Lexical => "lexical",
+            ErrorKind::Syntax => "syntax",
+            ErrorKind::Semantic => "semantic",
+            ErrorKind::Unsupported => "unsupported",
+        };
+
+        f.write_str(result)
+    }
+}
+
+/// Position of a fragment of SQL code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Span {
+    /// Byte offset of the first fragment character.
+    pub start: usize,
+    /// Byte offset right after the last fragment character.
+    pub end: usize,
+    /// Line number of the first fragment character (starting from 1).
+    pub line: usize,
+    /// Column number of the first fragment character (starting from 1).
+    pub column: usize,
+}
+
+impl Span {
+    /// Construct new `Span` object.
+    ///
+    /// # Parameters
+    /// - `start`  - given fragment start byte offset.
+    /// - `end`    - given fragment end byte offset.
+    /// - `line`   - given fragment start line number.
+    /// - `column` - given fragment start column number.
+    ///
+    /// # Returns
+    /// - New `Span` object.
+    pub const fn new(
+        start: usize,
+        end: usize,
+        line: usize,
+        column: usize,
+    ) -> Self {
+        Self {
+            start,
+            end,
+            line,
+            column,
+        }
+    }
+
+    /// Get span that covers both this and other span.
+    ///
+    /// # Parameters
+    /// - `other` - given span that follows this span.
+    ///
+    /// # Returns
+    /// - New `Span` object.
+    pub const fn merge(&self, other: &Span) -> Self {
+        Self {
+            start: self.start,
+            end: other.end,
+            line: self.line,
+            column: self.column,
+        }
+    }
+}
+
+/// TorusSQL error struct.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TorusError {
+    /// Error kind.
+    pub kind: ErrorKind,
+    /// Position of the offending SQL code fragment.
+    pub span: Span,
+    /// Error description.
+    pub message: String,
+}
+
+impl TorusError {
+    /// Construct new `TorusError` object.
+    ///
+    /// # Parameters
+    /// - `kind`    - given error kind.
+    /// - `span`    - given offending SQL code fragment position.
+    /// - `message` - given error description.
+    ///
+    /// # Returns
+    /// - New `TorusError` object.
+    pub fn new(
+        kind: ErrorKind,
+        span: Span,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            kind,
+            span,
+            message: message.into(),
+        }
+    }
+
+    /// Construct new syntax error describing unexpected SQL code fragment.
+    ///
+    /// # Parameters
+    /// - `span`     - given offending SQL code fragment position.
+    /// - `expected` - given description of expected fragment.
+    /// - `found`    - given found fragment.
+    ///
+    /// # Returns
+    /// - New `TorusError` object.
+    pub fn expected(span: Span, expected: &str, found: impl Display) -> Self {
+        let message = format!("expected {expected}, found {found}");
+        Self::new(ErrorKind::Syntax, span, message)
+    }
+
+    /// Render error with the offending line of SQL code and caret under it.
+    ///
+    /// # Parameters
+    /// - `source` - given SQL code in which error occurred.
+    ///
+    /// # Returns
+    /// - Human readable error diagnostics.
+    pub fn render(&self, source: &str) -> String {
+        let line = source
+            .lines()
+            .nth(self.span.line.saturating_sub(1))
+            .unwrap_or_default();
+
+        // Span column counts characters, while span offsets count bytes.
+        let fragment = source
+            .get(self.span.start..self.span.end)
+            .unwrap_or_default();
+
+        // Underline at least one character of the offending fragment.
+        let offset = self.span.column.saturating_sub(1);
+        let width = fragment.chars().count().max(1);
+        let rest = line.chars().count().saturating_sub(offset);
+        let width = width.min(rest.max(1));
+
+        format!(
+            "{self}\n{line}\n{}{}",
+            " ".repeat(offset),
+            "^".repeat(width)
+        )
+    }
+}
+
+impl Display for TorusError {
+    /// Display TorusSQL error.
+    ///
+    /// # Parameters
+    /// - `f` - given formatter.
+    ///
+    /// # Returns
+    /// - `OK`  - in case of success.
+    /// - `Err` - otherwise.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} error at {}:{}: {}",
+            self.kind, self.span.line, self.span.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for TorusError {}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let span = Span::new(7, 15, 1, 8);
+        let error = TorusError::expected(span, "DATABASE", "\"DATABSE\"");
+
+        assert_eq!(
+            error.to_string(),
+            "syntax error at 1:8: expected DATABASE, found \"DATABSE\""
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let source = "CREATE\nDATABSE \"MyDB\";";
+        let span = Span::new(7, 14, 2, 1);
+        let error = TorusError::expected(span, "DATABASE", "\"DATABSE\"");
+
+        let rendered = error.render(source);
+        let lines: Vec<_> = rendered.lines().collect();
+
+        assert_eq!(lines[1], "DATABSE \"MyDB\";");
+        assert_eq!(lines[2], "^^^^^^^");
+    }
+
+    #[test]
+    fn test_render_multibyte() {
+        let source = "SELECT 'é' 'héllo';";
+        let span = Span::new(12, 20, 1, 12);
+        let error = TorusError::expected(span, "','", "'héllo'");
+
+        let rendered = error.render(source);
+        let lines: Vec<_> = rendered.lines().collect();
+
+        assert_eq!(lines[2], format!("{}{}", " ".repeat(11), "^".repeat(7)));
+    }
+}
@@ -5,4 +5,5 @@
 
 //! TorusSQL Software Development Kit (SDK) main module.
 
+pub mod error;
 pub mod log;
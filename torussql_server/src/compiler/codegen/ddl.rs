@@ -6,39 +6,419 @@
 //! SQL DDL related commands code generation declarations.
 
 use super::language_type_to_bytecode;
-use crate::compiler::codegen::{Bytecode, statement_to_bytecode};
-use crate::compiler::parser::ast::{LanguageType, Statement};
+use crate::compiler::codegen::{
+    Bytecode, expr::push_expr, push_count, push_flag, push_name, push_names,
+    statement_to_bytecode,
+};
+use crate::compiler::parser::ast::{
+    AlterDatabaseAction, AlterTableAction, ColumnDef, Constraint,
+    ConstraintKind, DataType, DropBehavior, LanguageType, ReferentialAction,
+    Statement,
+};
+use torussql_sdk::error::Result;
 
 /// Generate bytecode for inner virtual machine.
 ///
 /// # Parameters
 /// - `bytecode`  - given bytecode to store.
 /// - `statement` - given SQL statement.
-pub fn generate_bytecode(bytecode: &mut Bytecode, statement: &Statement) {
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - in case of failure.
+pub fn generate_bytecode(
+    bytecode: &mut Bytecode,
+    statement: &Statement,
+) -> Result<()> {
     // Bytecode header.
     bytecode.push(language_type_to_bytecode(LanguageType::DDL));
     bytecode.push(statement_to_bytecode(statement));
 
     // Handle different types of SQL statements.
     match statement {
-        Statement::CreateDatabase { name } => {
-            generate_create_database(bytecode, name)
+        Statement::CreateDatabase {
+            name,
+            if_not_exists,
+        } => generate_create_database(bytecode, name, *if_not_exists),
+        Statement::DropDatabase { name, if_exists } => {
+            generate_drop_database(bytecode, name, *if_exists)
+        }
+        Statement::AlterDatabase { name, action } => {
+            generate_alter_database(bytecode, name, action)
         }
+        Statement::CreateTable {
+            name,
+            if_not_exists,
+            columns,
+            constraints,
+        } => generate_create_table(
+            bytecode,
+            name,
+            *if_not_exists,
+            columns,
+            constraints,
+        ),
+        Statement::AlterTable { name, action } => {
+            generate_alter_table(bytecode, name, action)
+        }
+        Statement::DropTable {
+            names,
+            if_exists,
+            behavior,
+        } => generate_drop_table(bytecode, names, *if_exists, *behavior),
+        Statement::Truncate { names } => push_names(bytecode, names),
+        _ => unreachable!("not a DDL statement: {statement:?}"),
     }
 }
 
 /// Generate bytecode CREATE DATABASE statement.
 ///
 /// # Parameters
+/// - `bytecode`      - given bytecode to store.
+/// - `name`          - given database name.
+/// - `if_not_exists` - given IF NOT EXISTS flag.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - in case of failure.
+fn generate_create_database(
+    bytecode: &mut Bytecode,
+    name: &str,
+    if_not_exists: bool,
+) -> Result<()> {
+    push_flag(bytecode, if_not_exists);
+
+    // Generate byte code for database name.
+    push_name(bytecode, name)?;
+
+    Ok(())
+}
+
+/// Generate bytecode DROP DATABASE statement.
+///
+/// # Parameters
+/// - `bytecode`  - given bytecode to store.
+/// - `name`      - given database name.
+/// - `if_exists` - given IF EXISTS flag.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - in case of failure.
+fn generate_drop_database(
+    bytecode: &mut Bytecode,
+    name: &str,
+    if_exists: bool,
+) -> Result<()> {
+    push_flag(bytecode, if_exists);
+    push_name(bytecode, name)?;
+
+    Ok(())
+}
+
+/// Generate bytecode ALTER DATABASE statement.
+///
+/// # Parameters
 /// - `bytecode` - given bytecode to store.
 /// - `name`     - given database name.
-fn generate_create_database(bytecode: &mut Bytecode, name: &String) {
-    // TODO: add bytecode for "IF NOT EXISTS".
+/// - `action`   - given change to apply.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - in case of failure.
+fn generate_alter_database(
+    bytecode: &mut Bytecode,
+    name: &str,
+    action: &AlterDatabaseAction,
+) -> Result<()> {
+    push_name(bytecode, name)?;
 
-    // Generate byte code for database name.
-    bytecode.push(name.len() as u8);
+    match action {
+        AlterDatabaseAction::RenameTo(new_name) => {
+            bytecode.push(0x01);
+            push_name(bytecode, new_name)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Generate bytecode CREATE TABLE statement.
+///
+/// # Parameters
+/// - `bytecode`      - given bytecode to store.
+/// - `name`          - given table name.
+/// - `if_not_exists` - given IF NOT EXISTS flag.
+/// - `columns`       - given table columns definitions.
+/// - `constraints`   - given table-level constraints.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - in case of failure.
+fn generate_create_table(
+    bytecode: &mut Bytecode,
+    name: &str,
+    if_not_exists: bool,
+    columns: &[ColumnDef],
+    constraints: &[Constraint],
+) -> Result<()> {
+    push_flag(bytecode, if_not_exists);
+    push_name(bytecode, name)?;
+    push_count(bytecode, columns.len())?;
+
+    for column in columns {
+        push_column_def(bytecode, column)?;
+    }
+
+    push_constraints(bytecode, constraints)?;
+
+    Ok(())
+}
+
+/// Generate bytecode ALTER TABLE statement.
+///
+/// # Parameters
+/// - `bytecode` - given bytecode to store.
+/// - `name`     - given table name.
+/// - `action`   - given change to apply.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - in case of failure.
+fn generate_alter_table(
+    bytecode: &mut Bytecode,
+    name: &str,
+    action: &AlterTableAction,
+) -> Result<()> {
+    push_name(bytecode, name)?;
 
-    for b in name.bytes() {
-        bytecode.push(b);
+    match action {
+        AlterTableAction::AddColumn(column) => {
+            bytecode.push(0x01);
+            push_column_def(bytecode, column)?;
+        }
+        AlterTableAction::DropColumn { name, behavior } => {
+            bytecode.push(0x02);
+            push_name(bytecode, name)?;
+            bytecode.push(drop_behavior_to_bytecode(*behavior));
+        }
+        AlterTableAction::RenameColumn { name, new_name } => {
+            bytecode.push(0x03);
+            push_name(bytecode, name)?;
+            push_name(bytecode, new_name)?;
+        }
+        AlterTableAction::AlterColumnType { name, data_type } => {
+            bytecode.push(0x04);
+            push_name(bytecode, name)?;
+            push_data_type(bytecode, data_type);
+        }
+        AlterTableAction::SetColumnDefault { name, default } => {
+            bytecode.push(0x05);
+            push_name(bytecode, name)?;
+            push_expr(bytecode, default)?;
+        }
+        AlterTableAction::DropColumnNotNull(name) => {
+            bytecode.push(0x06);
+            push_name(bytecode, name)?;
+        }
+        AlterTableAction::AddConstraint(constraint) => {
+            bytecode.push(0x07);
+            push_constraint(bytecode, constraint)?;
+        }
+        AlterTableAction::DropConstraint { name, behavior } => {
+            bytecode.push(0x08);
+            push_name(bytecode, name)?;
+            bytecode.push(drop_behavior_to_bytecode(*behavior));
+        }
+        AlterTableAction::RenameTo(new_name) => {
+            bytecode.push(0x09);
+            push_name(bytecode, new_name)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Generate bytecode DROP TABLE statement.
+///
+/// # Parameters
+/// - `bytecode`  - given bytecode to store.
+/// - `names`     - given tables names.
+/// - `if_exists` - given IF EXISTS flag.
+/// - `behavior`  - given handling of dependent objects.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - in case of failure.
+fn generate_drop_table(
+    bytecode: &mut Bytecode,
+    names: &[String],
+    if_exists: bool,
+    behavior: DropBehavior,
+) -> Result<()> {
+    push_flag(bytecode, if_exists);
+    bytecode.push(drop_behavior_to_bytecode(behavior));
+    push_names(bytecode, names)?;
+
+    Ok(())
+}
+
+/// Generate bytecode for table column definition.
+///
+/// # Parameters
+/// - `bytecode` - given bytecode to store.
+/// - `column`   - given table column definition.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - in case of failure.
+fn push_column_def(bytecode: &mut Bytecode, column: &ColumnDef) -> Result<()> {
+    push_name(bytecode, &column.name)?;
+    push_data_type(bytecode, &column.data_type);
+    push_flag(bytecode, column.not_null);
+    push_flag(bytecode, column.default.is_some());
+
+    if let Some(default) = &column.default {
+        push_expr(bytecode, default)?;
+    }
+
+    push_constraints(bytecode, &column.constraints)?;
+
+    Ok(())
+}
+
+/// Generate bytecode for list of table constraints.
+///
+/// # Parameters
+/// - `bytecode`    - given bytecode to store.
+/// - `constraints` - given table constraints.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - in case of failure.
+fn push_constraints(
+    bytecode: &mut Bytecode,
+    constraints: &[Constraint],
+) -> Result<()> {
+    push_count(bytecode, constraints.len())?;
+
+    for constraint in constraints {
+        push_constraint(bytecode, constraint)?;
+    }
+
+    Ok(())
+}
+
+/// Generate bytecode for table constraint.
+///
+/// # Parameters
+/// - `bytecode`   - given bytecode to store.
+/// - `constraint` - given table constraint.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - in case of failure.
+fn push_constraint(
+    bytecode: &mut Bytecode,
+    constraint: &Constraint,
+) -> Result<()> {
+    push_flag(bytecode, constraint.name.is_some());
+
+    if let Some(name) = &constraint.name {
+        push_name(bytecode, name)?;
+    }
+
+    match &constraint.kind {
+        ConstraintKind::PrimaryKey(columns) => {
+            bytecode.push(0x01);
+            push_names(bytecode, columns)?;
+        }
+        ConstraintKind::Unique(columns) => {
+            bytecode.push(0x02);
+            push_names(bytecode, columns)?;
+        }
+        ConstraintKind::Check(expr) => {
+            bytecode.push(0x03);
+            push_expr(bytecode, expr)?;
+        }
+        ConstraintKind::ForeignKey {
+            columns,
+            table,
+            referred_columns,
+            on_delete,
+            on_update,
+        } => {
+            bytecode.push(0x04);
+            push_names(bytecode, columns)?;
+            push_name(bytecode, table)?;
+            // Empty list stands for primary key of referenced table.
+            push_names(bytecode, referred_columns)?;
+            bytecode.push(referential_action_to_bytecode(*on_delete));
+            bytecode.push(referential_action_to_bytecode(*on_update));
+        }
+    }
+
+    Ok(())
+}
+
+/// Convert foreign key referential action to bytecode unit.
+///
+/// # Parameters
+/// - `action` - given referential action to convert.
+///
+/// # Returns
+/// - `Bytecode unit representation of referential action`.
+const fn referential_action_to_bytecode(action: ReferentialAction) -> u8 {
+    match action {
+        ReferentialAction::NoAction => 0x00,
+        ReferentialAction::Restrict => 0x01,
+        ReferentialAction::Cascade => 0x02,
+        ReferentialAction::SetNull => 0x03,
+        ReferentialAction::SetDefault => 0x04,
+    }
+}
+
+/// Convert handling of dependent objects to bytecode unit.
+///
+/// # Parameters
+/// - `behavior` - given handling of dependent objects to convert.
+///
+/// # Returns
+/// - `Bytecode unit representation of drop behavior`.
+const fn drop_behavior_to_bytecode(behavior: DropBehavior) -> u8 {
+    match behavior {
+        DropBehavior::Restrict => 0x00,
+        DropBehavior::Cascade => 0x01,
+    }
+}
+
+/// Generate bytecode for SQL data type.
+///
+/// # Parameters
+/// - `bytecode`  - given bytecode to store.
+/// - `data_type` - given SQL data type.
+pub fn push_data_type(bytecode: &mut Bytecode, data_type: &DataType) {
+    match data_type {
+        DataType::SmallInt => bytecode.push(0x01),
+        DataType::Integer => bytecode.push(0x02),
+        DataType::BigInt => bytecode.push(0x03),
+        DataType::Real => bytecode.push(0x04),
+        DataType::Double => bytecode.push(0x05),
+        DataType::Decimal { precision, scale } => {
+            bytecode.push(0x06);
+            // Zero precision stands for implementation-defined precision.
+            bytecode.push(precision.unwrap_or(0));
+            bytecode.push(*scale);
+        }
+        DataType::Varchar(length) => {
+            bytecode.push(0x07);
+            bytecode.extend_from_slice(&length.to_le_bytes());
+        }
+        DataType::Text => bytecode.push(0x08),
+        DataType::Boolean => bytecode.push(0x09),
+        DataType::Date => bytecode.push(0x0A),
+        DataType::Time => bytecode.push(0x0B),
+        DataType::Timestamp => bytecode.push(0x0C),
+        DataType::Blob => bytecode.push(0x0D),
+        DataType::Interval => bytecode.push(0x0E),
     }
 }
@@ -0,0 +1,145 @@
+// Project name: TorusSQL.
+// Description: Relational database management system.
+// Licence: GPL-3.0.
+// Author: Alexander (@alkuzin).
+
+//! SQL DML related commands code generation declarations.
+
+use super::language_type_to_bytecode;
+use crate::compiler::codegen::{
+    Bytecode,
+    dql::push_query,
+    expr::{push_expr, push_exprs},
+    push_count, push_flag, push_name, push_names, statement_to_bytecode,
+};
+use crate::compiler::parser::ast::{
+    Assignment, Expr, InsertSource, LanguageType, Statement,
+};
+use torussql_sdk::error::Result;
+
+/// Generate bytecode for inner virtual machine.
+///
+/// # Parameters
+/// - `bytecode`  - given bytecode to store.
+/// - `statement` - given SQL statement.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - in case of failure.
+pub fn generate_bytecode(
+    bytecode: &mut Bytecode,
+    statement: &Statement,
+) -> Result<()> {
+    // Bytecode header.
+    bytecode.push(language_type_to_bytecode(LanguageType::DML));
+    bytecode.push(statement_to_bytecode(statement));
+
+    // Handle different types of SQL statements.
+    match statement {
+        Statement::Insert {
+            table,
+            columns,
+            source,
+        } => generate_insert(bytecode, table, columns, source),
+        Statement::Update {
+            table,
+            assignments,
+            selection,
+        } => generate_update(bytecode, table, assignments, selection),
+        Statement::Delete { table, selection } => {
+            push_name(bytecode, table)?;
+            push_selection(bytecode, selection)
+        }
+        _ => unreachable!("not a DML statement: {statement:?}"),
+    }
+}
+
+/// Generate bytecode INSERT statement.
+///
+/// # Parameters
+/// - `bytecode` - given bytecode to store.
+/// - `table`    - given table name.
+/// - `columns`  - given columns to fill.
+/// - `source`   - given rows to add.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - in case of failure.
+fn generate_insert(
+    bytecode: &mut Bytecode,
+    table: &str,
+    columns: &[String],
+    source: &InsertSource,
+) -> Result<()> {
+    push_name(bytecode, table)?;
+    push_names(bytecode, columns)?;
+
+    match source {
+        InsertSource::Values(rows) => {
+            bytecode.push(0x01);
+            push_count(bytecode, rows.len())?;
+
+            for row in rows {
+                push_exprs(bytecode, row)?;
+            }
+        }
+        InsertSource::Query(query) => {
+            bytecode.push(0x02);
+            push_query(bytecode, query)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Generate bytecode UPDATE statement.
+///
+/// # Parameters
+/// - `bytecode`    - given bytecode to store.
+/// - `table`       - given table name.
+/// - `assignments` - given new columns values.
+/// - `selection`   - given condition for rows to change.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - in case of failure.
+fn generate_update(
+    bytecode: &mut Bytecode,
+    table: &str,
+    assignments: &[Assignment],
+    selection: &Option<Expr>,
+) -> Result<()> {
+    push_name(bytecode, table)?;
+    push_count(bytecode, assignments.len())?;
+
+    for assignment in assignments {
+        push_name(bytecode, &assignment.column)?;
+        push_expr(bytecode, &assignment.value)?;
+    }
+
+    push_selection(bytecode, selection)?;
+
+    Ok(())
+}
+
+/// Generate bytecode for optional WHERE condition.
+///
+/// # Parameters
+/// - `bytecode`  - given bytecode to store.
+/// - `selection` - given condition for rows.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - in case of failure.
+pub fn push_selection(
+    bytecode: &mut Bytecode,
+    selection: &Option<Expr>,
+) -> Result<()> {
+    push_flag(bytecode, selection.is_some());
+
+    if let Some(selection) = selection {
+        push_expr(bytecode, selection)?;
+    }
+
+    Ok(())
+}
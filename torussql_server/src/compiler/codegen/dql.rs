@@ -0,0 +1,342 @@
+// Project name: TorusSQL.
+// Description: Relational database management system.
+// Licence: GPL-3.0.
+// Author: Alexander (@alkuzin).
+
+//! SQL DQL related commands code generation declarations.
+
+use super::language_type_to_bytecode;
+use crate::compiler::codegen::{
+    Bytecode,
+    dml::push_selection,
+    expr::{push_expr, push_exprs, push_window_spec},
+    push_count, push_flag, push_name, push_names, statement_to_bytecode,
+};
+use crate::compiler::parser::ast::{
+    JoinConstraint, JoinKind, LanguageType, OrderByExpr, Query, Select,
+    SelectItem, SetExpr, SetOperator, Statement, TableRef, With,
+};
+use torussql_sdk::error::Result;
+
+/// Generate bytecode for inner virtual machine.
+///
+/// # Parameters
+/// - `bytecode`  - given bytecode to store.
+/// - `statement` - given SQL statement.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - in case of failure.
+pub fn generate_bytecode(
+    bytecode: &mut Bytecode,
+    statement: &Statement,
+) -> Result<()> {
+    // Bytecode header.
+    bytecode.push(language_type_to_bytecode(LanguageType::DQL));
+    bytecode.push(statement_to_bytecode(statement));
+
+    match statement {
+        Statement::Query(query) => push_query(bytecode, query),
+        _ => unreachable!("not a DQL statement: {statement:?}"),
+    }
+}
+
+/// Generate bytecode for SELECT query.
+///
+/// # Parameters
+/// - `bytecode` - given bytecode to store.
+/// - `query`    - given SELECT query.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - in case of failure.
+pub fn push_query(bytecode: &mut Bytecode, query: &Query) -> Result<()> {
+    push_flag(bytecode, query.with.is_some());
+
+    if let Some(with) = &query.with {
+        push_with(bytecode, with)?;
+    }
+
+    push_set_expr(bytecode, &query.body)?;
+    push_count(bytecode, query.order_by.len())?;
+
+    for order_by in &query.order_by {
+        push_order_by_expr(bytecode, order_by)?;
+    }
+
+    push_row_count(bytecode, query.limit);
+    push_row_count(bytecode, query.offset);
+
+    Ok(())
+}
+
+/// Generate bytecode for WITH clause.
+///
+/// # Parameters
+/// - `bytecode` - given bytecode to store.
+/// - `with`     - given WITH clause.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - in case of failure.
+fn push_with(bytecode: &mut Bytecode, with: &With) -> Result<()> {
+    push_flag(bytecode, with.recursive);
+    push_count(bytecode, with.ctes.len())?;
+
+    for cte in &with.ctes {
+        push_name(bytecode, &cte.name)?;
+        push_names(bytecode, &cte.columns)?;
+        push_query(bytecode, &cte.query)?;
+    }
+
+    Ok(())
+}
+
+/// Generate bytecode for query body.
+///
+/// # Parameters
+/// - `bytecode` - given bytecode to store.
+/// - `body`     - given query body.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - in case of failure.
+fn push_set_expr(bytecode: &mut Bytecode, body: &SetExpr) -> Result<()> {
+    match body {
+        SetExpr::Select(select) => {
+            bytecode.push(0x01);
+            push_select(bytecode, select)?;
+        }
+        SetExpr::Query(query) => {
+            bytecode.push(0x02);
+            push_query(bytecode, query)?;
+        }
+        SetExpr::SetOperation {
+            op,
+            all,
+            left,
+            right,
+        } => {
+            bytecode.push(0x03);
+            bytecode.push(set_operator_to_bytecode(*op));
+            push_flag(bytecode, *all);
+            push_set_expr(bytecode, left)?;
+            push_set_expr(bytecode, right)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Convert set operator to bytecode unit.
+///
+/// # Parameters
+/// - `op` - given set operator to convert.
+///
+/// # Returns
+/// - `Bytecode unit representation of set operator`.
+const fn set_operator_to_bytecode(op: SetOperator) -> u8 {
+    match op {
+        SetOperator::Union => 0x01,
+        SetOperator::Intersect => 0x02,
+        SetOperator::Except => 0x03,
+    }
+}
+
+/// Generate bytecode for SELECT clause.
+///
+/// # Parameters
+/// - `bytecode` - given bytecode to store.
+/// - `select`   - given SELECT clause.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - in case of failure.
+fn push_select(bytecode: &mut Bytecode, select: &Select) -> Result<()> {
+    push_flag(bytecode, select.distinct);
+    push_count(bytecode, select.projection.len())?;
+
+    for item in &select.projection {
+        push_select_item(bytecode, item)?;
+    }
+
+    push_count(bytecode, select.from.len())?;
+
+    for table in &select.from {
+        push_table_ref(bytecode, table)?;
+    }
+
+    push_selection(bytecode, &select.selection)?;
+    push_exprs(bytecode, &select.group_by)?;
+    push_selection(bytecode, &select.having)?;
+    push_count(bytecode, select.windows.len())?;
+
+    for window in &select.windows {
+        push_name(bytecode, &window.name)?;
+        push_window_spec(bytecode, &window.spec)?;
+    }
+
+    Ok(())
+}
+
+/// Generate bytecode for value returned by SELECT query.
+///
+/// # Parameters
+/// - `bytecode` - given bytecode to store.
+/// - `item`     - given SELECT item.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - in case of failure.
+fn push_select_item(bytecode: &mut Bytecode, item: &SelectItem) -> Result<()> {
+    match item {
+        SelectItem::Expr { expr, alias } => {
+            bytecode.push(0x01);
+            push_expr(bytecode, expr)?;
+            push_alias(bytecode, alias)?;
+        }
+        SelectItem::Wildcard => bytecode.push(0x02),
+        SelectItem::QualifiedWildcard(table) => {
+            bytecode.push(0x03);
+            push_name(bytecode, table)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Generate bytecode for source of rows.
+///
+/// # Parameters
+/// - `bytecode` - given bytecode to store.
+/// - `table`    - given table reference.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - in case of failure.
+fn push_table_ref(bytecode: &mut Bytecode, table: &TableRef) -> Result<()> {
+    match table {
+        TableRef::Table { name, alias } => {
+            bytecode.push(0x01);
+            push_name(bytecode, name)?;
+            push_alias(bytecode, alias)?;
+        }
+        TableRef::Derived { query, alias } => {
+            bytecode.push(0x02);
+            push_query(bytecode, query)?;
+            push_name(bytecode, alias)?;
+        }
+        TableRef::Join {
+            left,
+            right,
+            kind,
+            constraint,
+        } => {
+            bytecode.push(0x03);
+            bytecode.push(join_kind_to_bytecode(*kind));
+            push_table_ref(bytecode, left)?;
+            push_table_ref(bytecode, right)?;
+            push_join_constraint(bytecode, constraint)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Generate bytecode for JOIN rows matching condition.
+///
+/// # Parameters
+/// - `bytecode`   - given bytecode to store.
+/// - `constraint` - given JOIN constraint.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - in case of failure.
+fn push_join_constraint(
+    bytecode: &mut Bytecode,
+    constraint: &JoinConstraint,
+) -> Result<()> {
+    match constraint {
+        JoinConstraint::None => bytecode.push(0x00),
+        JoinConstraint::On(expr) => {
+            bytecode.push(0x01);
+            push_expr(bytecode, expr)?;
+        }
+        JoinConstraint::Using(columns) => {
+            bytecode.push(0x02);
+            push_names(bytecode, columns)?;
+        }
+        JoinConstraint::Natural => bytecode.push(0x03),
+    }
+
+    Ok(())
+}
+
+/// Convert JOIN type to bytecode unit.
+///
+/// # Parameters
+/// - `kind` - given JOIN type to convert.
+///
+/// # Returns
+/// - `Bytecode unit representation of JOIN type`.
+const fn join_kind_to_bytecode(kind: JoinKind) -> u8 {
+    match kind {
+        JoinKind::Inner => 0x01,
+        JoinKind::Left => 0x02,
+        JoinKind::Right => 0x03,
+        JoinKind::Full => 0x04,
+        JoinKind::Cross => 0x05,
+    }
+}
+
+/// Generate bytecode for ORDER BY clause item.
+///
+/// # Parameters
+/// - `bytecode` - given bytecode to store.
+/// - `order_by` - given ORDER BY item.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - in case of failure.
+pub fn push_order_by_expr(
+    bytecode: &mut Bytecode,
+    order_by: &OrderByExpr,
+) -> Result<()> {
+    push_expr(bytecode, &order_by.expr)?;
+    push_flag(bytecode, order_by.descending);
+    push_flag(bytecode, order_by.nulls_first);
+
+    Ok(())
+}
+
+/// Generate bytecode for optional alias.
+///
+/// # Parameters
+/// - `bytecode` - given bytecode to store.
+/// - `alias`    - given alias.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - in case of failure.
+fn push_alias(bytecode: &mut Bytecode, alias: &Option<String>) -> Result<()> {
+    push_flag(bytecode, alias.is_some());
+
+    if let Some(alias) = alias {
+        push_name(bytecode, alias)?;
+    }
+
+    Ok(())
+}
+
+/// Generate bytecode for optional LIMIT/OFFSET row count.
+///
+/// # Parameters
+/// - `bytecode` - given bytecode to store.
+/// - `count`    - given number of rows.
+fn push_row_count(bytecode: &mut Bytecode, count: Option<u64>) {
+    push_flag(bytecode, count.is_some());
+
+    if let Some(count) = count {
+        bytecode.extend_from_slice(&count.to_le_bytes());
+    }
+}
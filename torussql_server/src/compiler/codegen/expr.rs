@@ -0,0 +1,440 @@
+// Project name: TorusSQL.
+// Description: Relational database management system.
+// Licence: GPL-3.0.
+// Author: Alexander (@alkuzin).
+
+//! SQL expressions code generation declarations.
+
+use crate::compiler::codegen::{
+    Bytecode,
+    ddl::push_data_type,
+    dql::{push_order_by_expr, push_query},
+    push_bytes, push_count, push_flag, push_length, push_name,
+};
+use crate::compiler::parser::ast::{
+    BinaryOperator, Expr, FrameBound, FrameUnits, FunctionArgs, Literal,
+    Quantifier, UnaryOperator, WindowSpec, WindowType,
+};
+use torussql_sdk::error::Result;
+
+/// Generate bytecode for SQL expression.
+///
+/// # Parameters
+/// - `bytecode` - given bytecode to store.
+/// - `expr`     - given SQL expression.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - in case of failure.
+pub fn push_expr(bytecode: &mut Bytecode, expr: &Expr) -> Result<()> {
+    match expr {
+        Expr::Literal(literal) => {
+            bytecode.push(0x01);
+            push_literal(bytecode, literal)?;
+        }
+        Expr::Column { table, name } => {
+            bytecode.push(0x02);
+            push_flag(bytecode, table.is_some());
+
+            if let Some(table) = table {
+                push_name(bytecode, table)?;
+            }
+
+            push_name(bytecode, name)?;
+        }
+        Expr::UnaryOp { op, expr } => {
+            bytecode.push(0x03);
+            bytecode.push(unary_operator_to_bytecode(*op));
+            push_expr(bytecode, expr)?;
+        }
+        Expr::BinaryOp { left, op, right } => {
+            bytecode.push(0x04);
+            bytecode.push(binary_operator_to_bytecode(*op));
+            push_expr(bytecode, left)?;
+            push_expr(bytecode, right)?;
+        }
+        Expr::IsNull { expr, negated } => {
+            bytecode.push(0x05);
+            push_flag(bytecode, *negated);
+            push_expr(bytecode, expr)?;
+        }
+        Expr::Between {
+            expr,
+            negated,
+            low,
+            high,
+        } => {
+            bytecode.push(0x06);
+            push_flag(bytecode, *negated);
+            push_expr(bytecode, expr)?;
+            push_expr(bytecode, low)?;
+            push_expr(bytecode, high)?;
+        }
+        Expr::InList {
+            expr,
+            negated,
+            list,
+        } => {
+            bytecode.push(0x07);
+            push_flag(bytecode, *negated);
+            push_expr(bytecode, expr)?;
+            push_exprs(bytecode, list)?;
+        }
+        Expr::Like {
+            expr,
+            negated,
+            case_insensitive,
+            pattern,
+            escape,
+        } => {
+            bytecode.push(0x08);
+            push_flag(bytecode, *negated);
+            push_flag(bytecode, *case_insensitive);
+            push_expr(bytecode, expr)?;
+            push_expr(bytecode, pattern)?;
+            push_flag(bytecode, escape.is_some());
+
+            if let Some(escape) = escape {
+                push_expr(bytecode, escape)?;
+            }
+        }
+        Expr::Function {
+            name,
+            args,
+            distinct,
+            filter,
+            over,
+        } => {
+            bytecode.push(0x09);
+            push_name(bytecode, name)?;
+
+            match args {
+                FunctionArgs::List(args) => {
+                    bytecode.push(0x01);
+                    push_exprs(bytecode, args)?;
+                }
+                FunctionArgs::Wildcard => bytecode.push(0x02),
+            }
+
+            push_flag(bytecode, *distinct);
+            push_flag(bytecode, filter.is_some());
+
+            if let Some(filter) = filter {
+                push_expr(bytecode, filter)?;
+            }
+
+            match over {
+                None => bytecode.push(0x00),
+                Some(WindowType::Named(name)) => {
+                    bytecode.push(0x01);
+                    push_name(bytecode, name)?;
+                }
+                Some(WindowType::Spec(spec)) => {
+                    bytecode.push(0x02);
+                    push_window_spec(bytecode, spec)?;
+                }
+            }
+        }
+        Expr::Subquery(subquery) => {
+            bytecode.push(0x0A);
+            push_query(bytecode, subquery)?;
+        }
+        Expr::InSubquery {
+            expr,
+            negated,
+            subquery,
+        } => {
+            bytecode.push(0x0B);
+            push_flag(bytecode, *negated);
+            push_expr(bytecode, expr)?;
+            push_query(bytecode, subquery)?;
+        }
+        Expr::Exists(subquery) => {
+            bytecode.push(0x0C);
+            push_query(bytecode, subquery)?;
+        }
+        Expr::Quantified {
+            left,
+            op,
+            quantifier,
+            subquery,
+        } => {
+            bytecode.push(0x0D);
+            bytecode.push(binary_operator_to_bytecode(*op));
+            bytecode.push(quantifier_to_bytecode(*quantifier));
+            push_expr(bytecode, left)?;
+            push_query(bytecode, subquery)?;
+        }
+        Expr::Case {
+            operand,
+            branches,
+            else_result,
+        } => {
+            bytecode.push(0x0E);
+            push_optional_expr(bytecode, operand.as_deref())?;
+            push_count(bytecode, branches.len())?;
+
+            for (when, then) in branches {
+                push_expr(bytecode, when)?;
+                push_expr(bytecode, then)?;
+            }
+
+            push_optional_expr(bytecode, else_result.as_deref())?;
+        }
+        Expr::Cast { expr, data_type } => {
+            bytecode.push(0x0F);
+            push_expr(bytecode, expr)?;
+            push_data_type(bytecode, data_type);
+        }
+        Expr::TypedLiteral { data_type, value } => {
+            bytecode.push(0x10);
+            push_data_type(bytecode, data_type);
+            push_bytes(bytecode, value.as_bytes())?;
+        }
+        Expr::Coalesce(args) => {
+            bytecode.push(0x11);
+            push_exprs(bytecode, args)?;
+        }
+        Expr::NullIf { left, right } => {
+            bytecode.push(0x12);
+            push_expr(bytecode, left)?;
+            push_expr(bytecode, right)?;
+        }
+        Expr::Placeholder(index) => {
+            bytecode.push(0x13);
+            push_flag(bytecode, index.is_some());
+
+            if let Some(index) = index {
+                bytecode.extend_from_slice(&index.to_le_bytes());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Generate bytecode for optional SQL expression.
+///
+/// # Parameters
+/// - `bytecode` - given bytecode to store.
+/// - `expr`     - given SQL expression.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - in case of failure.
+fn push_optional_expr(
+    bytecode: &mut Bytecode,
+    expr: Option<&Expr>,
+) -> Result<()> {
+    push_flag(bytecode, expr.is_some());
+
+    if let Some(expr) = expr {
+        push_expr(bytecode, expr)?;
+    }
+
+    Ok(())
+}
+
+/// Generate bytecode for list of SQL expressions.
+///
+/// # Parameters
+/// - `bytecode` - given bytecode to store.
+/// - `exprs`    - given SQL expressions.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - in case of failure.
+pub fn push_exprs(bytecode: &mut Bytecode, exprs: &[Expr]) -> Result<()> {
+    push_count(bytecode, exprs.len())?;
+
+    for expr in exprs {
+        push_expr(bytecode, expr)?;
+    }
+
+    Ok(())
+}
+
+/// Generate bytecode for window definition.
+///
+/// # Parameters
+/// - `bytecode` - given bytecode to store.
+/// - `spec`     - given window definition.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - in case of failure.
+pub fn push_window_spec(
+    bytecode: &mut Bytecode,
+    spec: &WindowSpec,
+) -> Result<()> {
+    push_flag(bytecode, spec.base.is_some());
+
+    if let Some(base) = &spec.base {
+        push_name(bytecode, base)?;
+    }
+
+    push_exprs(bytecode, &spec.partition_by)?;
+    push_count(bytecode, spec.order_by.len())?;
+
+    for order_by in &spec.order_by {
+        push_order_by_expr(bytecode, order_by)?;
+    }
+
+    push_flag(bytecode, spec.frame.is_some());
+
+    if let Some(frame) = &spec.frame {
+        bytecode.push(frame_units_to_bytecode(frame.units));
+        push_frame_bound(bytecode, &frame.start)?;
+        push_frame_bound(bytecode, &frame.end)?;
+    }
+
+    Ok(())
+}
+
+/// Generate bytecode for window frame bound.
+///
+/// # Parameters
+/// - `bytecode` - given bytecode to store.
+/// - `bound`    - given window frame bound.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - in case of failure.
+fn push_frame_bound(bytecode: &mut Bytecode, bound: &FrameBound) -> Result<()> {
+    match bound {
+        FrameBound::UnboundedPreceding => bytecode.push(0x01),
+        FrameBound::Preceding(offset) => {
+            bytecode.push(0x02);
+            push_expr(bytecode, offset)?;
+        }
+        FrameBound::CurrentRow => bytecode.push(0x03),
+        FrameBound::Following(offset) => {
+            bytecode.push(0x04);
+            push_expr(bytecode, offset)?;
+        }
+        FrameBound::UnboundedFollowing => bytecode.push(0x05),
+    }
+
+    Ok(())
+}
+
+/// Convert window frame units to bytecode unit.
+///
+/// # Parameters
+/// - `units` - given window frame units to convert.
+///
+/// # Returns
+/// - `Bytecode unit representation of window frame units`.
+const fn frame_units_to_bytecode(units: FrameUnits) -> u8 {
+    match units {
+        FrameUnits::Rows => 0x01,
+        FrameUnits::Range => 0x02,
+        FrameUnits::Groups => 0x03,
+    }
+}
+
+/// Convert subquery comparison quantifier to bytecode unit.
+///
+/// # Parameters
+/// - `quantifier` - given quantifier to convert.
+///
+/// # Returns
+/// - `Bytecode unit representation of quantifier`.
+const fn quantifier_to_bytecode(quantifier: Quantifier) -> u8 {
+    match quantifier {
+        Quantifier::Any => 0x01,
+        Quantifier::All => 0x02,
+    }
+}
+
+/// Convert SQL unary operator to bytecode unit.
+///
+/// # Parameters
+/// - `op` - given SQL unary operator to convert.
+///
+/// # Returns
+/// - `Bytecode unit representation of SQL unary operator`.
+const fn unary_operator_to_bytecode(op: UnaryOperator) -> u8 {
+    match op {
+        UnaryOperator::Plus => 0x01,
+        UnaryOperator::Minus => 0x02,
+        UnaryOperator::Not => 0x03,
+    }
+}
+
+/// Convert SQL binary operator to bytecode unit.
+///
+/// # Parameters
+/// - `op` - given SQL binary operator to convert.
+///
+/// # Returns
+/// - `Bytecode unit representation of SQL binary operator`.
+const fn binary_operator_to_bytecode(op: BinaryOperator) -> u8 {
+    match op {
+        BinaryOperator::Add => 0x01,
+        BinaryOperator::Subtract => 0x02,
+        BinaryOperator::Multiply => 0x03,
+        BinaryOperator::Divide => 0x04,
+        BinaryOperator::Modulo => 0x05,
+        BinaryOperator::Concat => 0x06,
+        BinaryOperator::Equal => 0x07,
+        BinaryOperator::NotEqual => 0x08,
+        BinaryOperator::Less => 0x09,
+        BinaryOperator::LessEqual => 0x0A,
+        BinaryOperator::Greater => 0x0B,
+        BinaryOperator::GreaterEqual => 0x0C,
+        BinaryOperator::And => 0x0D,
+        BinaryOperator::Or => 0x0E,
+    }
+}
+
+/// Generate bytecode for SQL literal value.
+///
+/// # Parameters
+/// - `bytecode` - given bytecode to store.
+/// - `literal`  - given SQL literal value.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - in case of failure.
+fn push_literal(bytecode: &mut Bytecode, literal: &Literal) -> Result<()> {
+    match literal {
+        Literal::Null => bytecode.push(0x00),
+        Literal::Boolean(value) => {
+            bytecode.push(0x01);
+            push_flag(bytecode, *value);
+        }
+        Literal::Integer(value) => {
+            bytecode.push(0x02);
+            bytecode.extend_from_slice(&value.to_le_bytes());
+        }
+        Literal::Float(value) => {
+            bytecode.push(0x03);
+            bytecode.extend_from_slice(&value.to_le_bytes());
+        }
+        Literal::String(value) => {
+            bytecode.push(0x04);
+            push_bytes(bytecode, value.as_bytes())?;
+        }
+        Literal::Blob(value) => {
+            bytecode.push(0x05);
+            push_bytes(bytecode, value)?;
+        }
+        Literal::BitString(bits) => {
+            bytecode.push(0x06);
+            push_length(bytecode, bits.len())?;
+
+            // Pack bits starting from the most significant one.
+            for chunk in bits.chunks(8) {
+                let byte =
+                    chunk.iter().enumerate().fold(0u8, |byte, (i, &bit)| {
+                        byte | ((bit as u8) << (7 - i))
+                    });
+
+                bytecode.push(byte);
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -6,10 +6,16 @@
 //! SQL code generation related declarations.
 
 pub mod ddl;
+pub mod dml;
+pub mod dql;
+pub mod expr;
+pub mod vendor;
 
+use crate::compiler::lexer::token::Spanned;
 use crate::compiler::parser::Parser;
 use crate::compiler::parser::ast::{LanguageType, Statement};
 use crate::log;
+use torussql_sdk::error::{ErrorKind, Result, Span, TorusError};
 
 // TODO: implement OpCode & BytecodeInstruction structs.
 // TODO: implement trait that implements method to_bytecode().
@@ -46,29 +52,43 @@ impl<'a> CodeGen<'a> {
     ///
     /// # Returns
     /// - `Bytecode` - in case of success.
-    /// - `None`     - in case of failure.
-    pub fn generate_bytecode(&mut self) -> Option<Bytecode> {
-        if let Some(statement) = self.parser.parse() {
-            let language_type = statement.language_type();
-
-            log::debug!("Statement: {:?}", statement);
-            log::debug!("Language type: {:?}", language_type);
-
-            match language_type {
-                LanguageType::DDL => {
-                    ddl::generate_bytecode(&mut self.bytecode, &statement)
-                }
-                LanguageType::DML => return None,
-                LanguageType::DCL => return None,
-                LanguageType::TCL => return None,
-                LanguageType::DQL => return None,
-                LanguageType::Vendor => return None,
-            };
-
-            return Some(self.bytecode.clone());
-        }
+    /// - `Err`      - in case of failure.
+    pub fn generate_bytecode(&mut self) -> Result<Bytecode> {
+        let Spanned {
+            value: statement,
+            span,
+        } = self.parser.parse_spanned()?;
+        let language_type = statement.language_type();
+        self.bytecode.clear();
+
+        log::debug!("Statement: {:?}", statement);
+        log::debug!("Language type: {:?}", language_type);
+
+        let result = match language_type {
+            LanguageType::DDL => {
+                ddl::generate_bytecode(&mut self.bytecode, &statement)
+            }
+            LanguageType::DML => {
+                dml::generate_bytecode(&mut self.bytecode, &statement)
+            }
+            LanguageType::DQL => {
+                dql::generate_bytecode(&mut self.bytecode, &statement)
+            }
+            LanguageType::Vendor => {
+                vendor::generate_bytecode(&mut self.bytecode, &statement)
+            }
+            _ => {
+                let message = format!("{language_type:?} is not supported");
+                let error =
+                    TorusError::new(ErrorKind::Unsupported, span, message);
+                return Err(error);
+            }
+        };
 
-        None
+        // Code generation errors refer to the whole statement.
+        result.map_err(|error| TorusError { span, ..error })?;
+
+        Ok(self.bytecode.clone())
     }
 }
 
@@ -120,17 +140,155 @@ pub const fn bytecode_to_language_type(byte: u8) -> Option<LanguageType> {
 pub const fn statement_to_bytecode(statement: &Statement) -> u8 {
     match statement {
         Statement::CreateDatabase { .. } => 0x01,
+        Statement::DropDatabase { .. } => 0x02,
+        Statement::AlterDatabase { .. } => 0x03,
+        Statement::UseDatabase { .. } => 0x04,
+        Statement::CreateTable { .. } => 0x05,
+        Statement::AlterTable { .. } => 0x06,
+        Statement::DropTable { .. } => 0x07,
+        Statement::Truncate { .. } => 0x08,
+        Statement::Insert { .. } => 0x09,
+        Statement::Update { .. } => 0x0A,
+        Statement::Delete { .. } => 0x0B,
+        Statement::Query(_) => 0x0C,
     }
 }
 
+/// Generate bytecode for name.
+///
+/// # Parameters
+/// - `bytecode` - given bytecode to store.
+/// - `name`     - given name (at most 255 bytes long).
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - in case of too long name.
+pub fn push_name(bytecode: &mut Bytecode, name: &str) -> Result<()> {
+    let Ok(length) = u8::try_from(name.len()) else {
+        return Err(limit_exceeded(format!(
+            "name is longer than {} bytes",
+            u8::MAX
+        )));
+    };
+
+    bytecode.push(length);
+    bytecode.extend_from_slice(name.as_bytes());
+
+    Ok(())
+}
+
+/// Generate bytecode for list of names.
+///
+/// # Parameters
+/// - `bytecode` - given bytecode to store.
+/// - `names`    - given names.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - in case of failure.
+pub fn push_names(bytecode: &mut Bytecode, names: &[String]) -> Result<()> {
+    push_count(bytecode, names.len())?;
+
+    for name in names {
+        push_name(bytecode, name)?;
+    }
+
+    Ok(())
+}
+
+/// Generate bytecode for arbitrary sequence of bytes.
+///
+/// # Parameters
+/// - `bytecode` - given bytecode to store.
+/// - `bytes`    - given sequence of bytes.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - in case of too long sequence.
+pub fn push_bytes(bytecode: &mut Bytecode, bytes: &[u8]) -> Result<()> {
+    push_length(bytecode, bytes.len())?;
+    bytecode.extend_from_slice(bytes);
+
+    Ok(())
+}
+
+/// Generate bytecode for length of value that follows it.
+///
+/// # Parameters
+/// - `bytecode` - given bytecode to store.
+/// - `length`   - given length (at most 4294967295).
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - in case of too big length.
+pub fn push_length(bytecode: &mut Bytecode, length: usize) -> Result<()> {
+    let Ok(length) = u32::try_from(length) else {
+        return Err(limit_exceeded(format!(
+            "value is longer than {} units",
+            u32::MAX
+        )));
+    };
+
+    bytecode.extend_from_slice(&length.to_le_bytes());
+
+    Ok(())
+}
+
+/// Generate bytecode for number of items that follow it.
+///
+/// # Parameters
+/// - `bytecode` - given bytecode to store.
+/// - `count`    - given number of items (at most 65535).
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - in case of too many items.
+pub fn push_count(bytecode: &mut Bytecode, count: usize) -> Result<()> {
+    let Ok(count) = u16::try_from(count) else {
+        return Err(limit_exceeded(format!(
+            "list contains more than {} items",
+            u16::MAX
+        )));
+    };
+
+    bytecode.extend_from_slice(&count.to_le_bytes());
+
+    Ok(())
+}
+
+/// Construct error describing value that does not fit into bytecode.
+///
+/// # Parameters
+/// - `message` - given error message.
+///
+/// # Returns
+/// - New unsupported feature error.
+fn limit_exceeded(message: String) -> TorusError {
+    // Position is set by `CodeGen` to the whole statement.
+    TorusError::new(ErrorKind::Unsupported, Span::default(), message)
+}
+
+/// Generate bytecode for boolean flag.
+///
+/// # Parameters
+/// - `bytecode` - given bytecode to store.
+/// - `flag`     - given flag.
+#[inline(always)]
+pub fn push_flag(bytecode: &mut Bytecode, flag: bool) {
+    bytecode.push(flag as u8);
+}
+
 #[cfg(test)]
 pub mod tests {
     use crate::compiler::codegen::CodeGen;
     use crate::compiler::lexer::Lexer;
     use crate::compiler::parser::Parser;
-    use torussql_sdk::log;
+    use torussql_sdk::{
+        error::{ErrorKind, Span},
+        log,
+    };
 
-    fn create_codegen(input: &str) -> CodeGen {
+    fn create_codegen(input: &str) -> CodeGen<'_> {
         let lexer = Lexer::new(input);
         let parser = Parser::new(lexer);
 
@@ -143,5 +301,427 @@ pub mod tests {
         let bytecode = codegen.generate_bytecode().unwrap();
 
         log::debug!("Bytecode: {:X?}", bytecode);
+        assert_eq!(bytecode, [0x01, 0x01, 0x00, 0x04, b'M', b'y', b'D', b'B']);
+    }
+
+    #[test]
+    fn test_codegen_database_statements() {
+        let inputs: [(&str, &[u8]); 4] = [
+            (
+                "CREATE DATABASE IF NOT EXISTS db",
+                &[0x01, 0x01, 0x01, 0x02, b'd', b'b'],
+            ),
+            (
+                "DROP DATABASE IF EXISTS db",
+                &[0x01, 0x02, 0x01, 0x02, b'd', b'b'],
+            ),
+            (
+                "ALTER DATABASE a RENAME TO b",
+                &[0x01, 0x03, 0x01, b'a', 0x01, 0x01, b'b'],
+            ),
+            ("USE db", &[0x06, 0x04, 0x02, b'd', b'b']),
+        ];
+
+        for (input, correct_bytecode) in inputs {
+            let bytecode = create_codegen(input).generate_bytecode().unwrap();
+            assert_eq!(bytecode, correct_bytecode, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_codegen_create_table() {
+        let input = "CREATE TABLE t (id INT NOT NULL, \
+                     name VARCHAR(300) DEFAULT 'x', price DECIMAL(5, 2))";
+        let bytecode = create_codegen(input).generate_bytecode().unwrap();
+
+        #[rustfmt::skip]
+        let correct_bytecode = [
+            // Header & flags.
+            0x01, 0x05, 0x00, 0x01, b't', 0x03, 0x00,
+            // id INT NOT NULL
+            0x02, b'i', b'd', 0x02, 0x01, 0x00, 0x00, 0x00,
+            // name VARCHAR(300) DEFAULT 'x'
+            0x04, b'n', b'a', b'm', b'e', 0x07, 0x2C, 0x01, 0x00, 0x00, 0x00,
+            0x01, 0x01, 0x04, 0x01, 0x00, 0x00, 0x00, b'x', 0x00, 0x00,
+            // price DECIMAL(5, 2)
+            0x05, b'p', b'r', b'i', b'c', b'e', 0x06, 0x05, 0x02, 0x00, 0x00,
+            0x00, 0x00,
+            // No table constraints.
+            0x00, 0x00,
+        ];
+
+        assert_eq!(bytecode, correct_bytecode);
+    }
+
+    #[test]
+    fn test_codegen_table_constraints() {
+        let input = "CREATE TABLE t (a INT PRIMARY KEY, \
+                     CONSTRAINT f FOREIGN KEY (a) REFERENCES u (b) \
+                     ON DELETE SET NULL)";
+        let bytecode = create_codegen(input).generate_bytecode().unwrap();
+
+        #[rustfmt::skip]
+        let correct_bytecode = [
+            // Header & flags.
+            0x01, 0x05, 0x00, 0x01, b't', 0x01, 0x00,
+            // a INT PRIMARY KEY
+            0x01, b'a', 0x02, 0x00, 0x00, 0x01, 0x00,
+            0x00, 0x01, 0x01, 0x00, 0x01, b'a',
+            // CONSTRAINT f FOREIGN KEY (a) REFERENCES u (b) ON DELETE SET NULL
+            0x01, 0x00, 0x01, 0x01, b'f', 0x04, 0x01, 0x00, 0x01, b'a',
+            0x01, b'u', 0x01, 0x00, 0x01, b'b', 0x03, 0x00,
+        ];
+
+        assert_eq!(bytecode, correct_bytecode);
+    }
+
+    #[test]
+    fn test_codegen_table_statements() {
+        let inputs: [(&str, &[u8]); 4] = [
+            (
+                "ALTER TABLE t RENAME COLUMN a TO b",
+                &[0x01, 0x06, 0x01, b't', 0x03, 0x01, b'a', 0x01, b'b'],
+            ),
+            (
+                "ALTER TABLE t ALTER COLUMN a TYPE BIGINT",
+                &[0x01, 0x06, 0x01, b't', 0x04, 0x01, b'a', 0x03],
+            ),
+            (
+                "DROP TABLE IF EXISTS a, b CASCADE",
+                &[0x01, 0x07, 0x01, 0x01, 0x02, 0x00, 0x01, b'a', 0x01, b'b'],
+            ),
+            ("TRUNCATE t", &[0x01, 0x08, 0x01, 0x00, 0x01, b't']),
+        ];
+
+        for (input, correct_bytecode) in inputs {
+            let bytecode = create_codegen(input).generate_bytecode().unwrap();
+            assert_eq!(bytecode, correct_bytecode, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_codegen_expression() {
+        let input = "ALTER TABLE t ADD CHECK (NOT t.a > -1)";
+        let bytecode = create_codegen(input).generate_bytecode().unwrap();
+
+        #[rustfmt::skip]
+        let correct_bytecode = [
+            // Header & ADD CHECK.
+            0x01, 0x06, 0x01, b't', 0x07, 0x00, 0x03,
+            // NOT (t.a > -1)
+            0x03, 0x03, 0x04, 0x0B,
+            0x02, 0x01, 0x01, b't', 0x01, b'a',
+            0x01, 0x02, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+        ];
+
+        assert_eq!(bytecode, correct_bytecode);
+    }
+
+    #[test]
+    fn test_codegen_placeholder() {
+        let input = "DELETE FROM t WHERE a = $2 OR b = ?";
+        let bytecode = create_codegen(input).generate_bytecode().unwrap();
+
+        #[rustfmt::skip]
+        let correct_bytecode = [
+            // Header, WHERE & OR.
+            0x02, 0x0B, 0x01, b't', 0x01, 0x04, 0x0E,
+            // a = $2
+            0x04, 0x07, 0x02, 0x00, 0x01, b'a',
+            0x13, 0x01, 0x02, 0x00, 0x00, 0x00,
+            // b = ?
+            0x04, 0x07, 0x02, 0x00, 0x01, b'b', 0x13, 0x00,
+        ];
+
+        assert_eq!(bytecode, correct_bytecode);
+    }
+
+    #[test]
+    fn test_codegen_conditional_expression() {
+        let input = "DELETE FROM t WHERE \
+                     CASE WHEN a THEN b::TEXT ELSE COALESCE(TIME '10:00') END";
+        let bytecode = create_codegen(input).generate_bytecode().unwrap();
+
+        #[rustfmt::skip]
+        let correct_bytecode = [
+            // Header, WHERE & CASE.
+            0x02, 0x0B, 0x01, b't', 0x01, 0x0E, 0x00, 0x01, 0x00,
+            // WHEN a THEN b::TEXT
+            0x02, 0x00, 0x01, b'a', 0x0F, 0x02, 0x00, 0x01, b'b', 0x08,
+            // ELSE COALESCE(TIME '10:00')
+            0x01, 0x11, 0x01, 0x00, 0x10, 0x0B, 0x05, 0x00, 0x00, 0x00,
+            b'1', b'0', b':', b'0', b'0',
+        ];
+
+        assert_eq!(bytecode, correct_bytecode);
+    }
+
+    #[test]
+    fn test_codegen_dml_statements() {
+        #[rustfmt::skip]
+        let inputs: [(&str, &[u8]); 4] = [
+            (
+                "INSERT INTO t (a) VALUES (TRUE), (FALSE)",
+                &[
+                    0x02, 0x09, 0x01, b't', 0x01, 0x00, 0x01, b'a',
+                    // VALUES
+                    0x01, 0x02, 0x00,
+                    0x01, 0x00, 0x01, 0x01, 0x01,
+                    0x01, 0x00, 0x01, 0x01, 0x00,
+                ],
+            ),
+            (
+                "INSERT INTO t SELECT * FROM s",
+                &[
+                    0x02, 0x09, 0x01, b't', 0x00, 0x00,
+                    // SELECT * FROM s
+                    0x02, 0x00, 0x01, 0x00, 0x01, 0x00, 0x02,
+                    0x01, 0x00, 0x01, 0x01, b's', 0x00,
+                    // No WHERE, GROUP BY, HAVING, WINDOW, ORDER BY, LIMIT &
+                    // OFFSET.
+                    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                ],
+            ),
+            (
+                "UPDATE t SET a = NULL",
+                &[
+                    0x02, 0x0A, 0x01, b't', 0x01, 0x00,
+                    0x01, b'a', 0x01, 0x00, 0x00,
+                ],
+            ),
+            (
+                "DELETE FROM t WHERE a",
+                &[
+                    0x02, 0x0B, 0x01, b't',
+                    0x01, 0x02, 0x00, 0x01, b'a',
+                ],
+            ),
+        ];
+
+        for (input, correct_bytecode) in inputs {
+            let bytecode = create_codegen(input).generate_bytecode().unwrap();
+            assert_eq!(bytecode, correct_bytecode, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_codegen_select() {
+        let input = "SELECT DISTINCT t.*, a AS b FROM t \
+                     ORDER BY a DESC NULLS LAST LIMIT 10 OFFSET 2";
+        let bytecode = create_codegen(input).generate_bytecode().unwrap();
+
+        #[rustfmt::skip]
+        let correct_bytecode = [
+            // Header, no WITH, SELECT clause & DISTINCT.
+            0x05, 0x0C, 0x00, 0x01, 0x01,
+            // t.*, a AS b
+            0x02, 0x00, 0x03, 0x01, b't',
+            0x01, 0x02, 0x00, 0x01, b'a', 0x01, 0x01, b'b',
+            // FROM t, no WHERE, GROUP BY, HAVING & WINDOW
+            0x01, 0x00, 0x01, 0x01, b't',
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            // ORDER BY a DESC NULLS LAST
+            0x01, 0x00, 0x02, 0x00, 0x01, b'a', 0x01, 0x00,
+            // LIMIT 10 OFFSET 2
+            0x01, 0x0A, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x01, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        assert_eq!(bytecode, correct_bytecode);
+    }
+
+    #[test]
+    fn test_codegen_join() {
+        let input = "SELECT * FROM a LEFT JOIN b USING (c)";
+        let bytecode = create_codegen(input).generate_bytecode().unwrap();
+
+        #[rustfmt::skip]
+        let correct_bytecode = [
+            // Header, SELECT *
+            0x05, 0x0C, 0x00, 0x01, 0x00, 0x01, 0x00, 0x02,
+            // FROM a LEFT JOIN b USING (c)
+            0x01, 0x00, 0x03, 0x02,
+            0x01, 0x01, b'a', 0x00,
+            0x01, 0x01, b'b', 0x00,
+            0x02, 0x01, 0x00, 0x01, b'c',
+            // No WHERE, GROUP BY, HAVING, WINDOW, ORDER BY, LIMIT & OFFSET.
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        assert_eq!(bytecode, correct_bytecode);
+    }
+
+    #[test]
+    fn test_codegen_aggregate() {
+        let input = "SELECT a, COUNT(*) FILTER (WHERE b) FROM t GROUP BY 1";
+        let bytecode = create_codegen(input).generate_bytecode().unwrap();
+
+        #[rustfmt::skip]
+        let correct_bytecode = [
+            // Header, a
+            0x05, 0x0C, 0x00, 0x01, 0x00, 0x02, 0x00,
+            0x01, 0x02, 0x00, 0x01, b'a', 0x00,
+            // COUNT(*) FILTER (WHERE b)
+            0x01, 0x09, 0x05, b'c', b'o', b'u', b'n', b't', 0x02, 0x00,
+            0x01, 0x02, 0x00, 0x01, b'b', 0x00, 0x00,
+            // FROM t, no WHERE
+            0x01, 0x00, 0x01, 0x01, b't', 0x00, 0x00,
+            // GROUP BY 1
+            0x01, 0x00, 0x01, 0x02, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00,
+            // No HAVING, WINDOW, ORDER BY, LIMIT & OFFSET.
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        assert_eq!(bytecode, correct_bytecode);
+    }
+
+    #[test]
+    fn test_codegen_window() {
+        let input = "SELECT rank() OVER (PARTITION BY a ROWS 1 PRECEDING)";
+        let bytecode = create_codegen(input).generate_bytecode().unwrap();
+
+        #[rustfmt::skip]
+        let correct_bytecode = [
+            // Header, rank()
+            0x05, 0x0C, 0x00, 0x01, 0x00, 0x01, 0x00,
+            0x01, 0x09, 0x04, b'r', b'a', b'n', b'k', 0x01, 0x00, 0x00,
+            0x00, 0x00,
+            // OVER (PARTITION BY a
+            0x02, 0x00, 0x01, 0x00, 0x02, 0x00, 0x01, b'a', 0x00, 0x00,
+            // ROWS 1 PRECEDING)
+            0x01, 0x01, 0x02,
+            0x01, 0x02, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x03,
+            // No alias, FROM, WHERE, GROUP BY, HAVING, WINDOW, ORDER BY,
+            // LIMIT & OFFSET.
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00,
+        ];
+
+        assert_eq!(bytecode, correct_bytecode);
+    }
+
+    #[test]
+    fn test_codegen_set_operation() {
+        let input = "SELECT 1 UNION ALL (SELECT 2)";
+        let bytecode = create_codegen(input).generate_bytecode().unwrap();
+
+        #[rustfmt::skip]
+        let correct_bytecode = [
+            // Header, UNION ALL
+            0x05, 0x0C, 0x00, 0x03, 0x01, 0x01,
+            // SELECT 1
+            0x01, 0x00, 0x01, 0x00, 0x01, 0x01, 0x02,
+            0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            // (SELECT 2)
+            0x02, 0x00, 0x01, 0x00, 0x01, 0x00, 0x01, 0x01, 0x02,
+            0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            // No ORDER BY, LIMIT & OFFSET in both queries.
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        assert_eq!(bytecode, correct_bytecode);
+    }
+
+    #[test]
+    fn test_codegen_with() {
+        let input = "WITH RECURSIVE a (x) AS (SELECT 1) SELECT *";
+        let bytecode = create_codegen(input).generate_bytecode().unwrap();
+
+        #[rustfmt::skip]
+        let correct_bytecode = [
+            // Header & WITH RECURSIVE.
+            0x05, 0x0C, 0x01, 0x01, 0x01, 0x00,
+            // a (x) AS (SELECT 1)
+            0x01, b'a', 0x01, 0x00, 0x01, b'x',
+            0x00, 0x01, 0x00, 0x01, 0x00, 0x01, 0x01, 0x02,
+            0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00,
+            // SELECT *
+            0x01, 0x00, 0x01, 0x00, 0x02,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00,
+        ];
+
+        assert_eq!(bytecode, correct_bytecode);
+    }
+
+    #[test]
+    fn test_codegen_subquery() {
+        let input = "DELETE FROM t WHERE a > ALL (SELECT b)";
+        let bytecode = create_codegen(input).generate_bytecode().unwrap();
+
+        #[rustfmt::skip]
+        let correct_bytecode = [
+            // Header & WHERE.
+            0x02, 0x0B, 0x01, b't', 0x01,
+            // a > ALL
+            0x0D, 0x0B, 0x02, 0x02, 0x00, 0x01, b'a',
+            // (SELECT b)
+            0x00, 0x01, 0x00, 0x01, 0x00,
+            0x01, 0x02, 0x00, 0x01, b'b', 0x00,
+            // No FROM, WHERE, GROUP BY, HAVING, WINDOW, ORDER BY, LIMIT &
+            // OFFSET.
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00,
+        ];
+
+        assert_eq!(bytecode, correct_bytecode);
+    }
+
+    #[test]
+    fn test_codegen_trailing_tokens() {
+        let inputs = [
+            ("DELETE FROM t WHRE id = 1", 15),
+            ("DROP TABLE a b c", 14),
+            ("SELECT a FROM t OFFSET 2 LIMIT 1", 26),
+            ("SELECT * FROM a NATURAL JOIN b ON TRUE", 32),
+            ("USE a b", 7),
+        ];
+
+        for (input, column) in inputs {
+            let mut codegen = create_codegen(input);
+            let error = codegen.generate_bytecode().unwrap_err();
+
+            assert_eq!(error.kind, ErrorKind::Syntax, "{input}");
+            assert_eq!(error.span.column, column, "{input}");
+        }
+
+        // Statement may be terminated with semicolon.
+        assert!(create_codegen("USE a;").generate_bytecode().is_ok());
+    }
+
+    #[test]
+    fn test_codegen_count_limit() {
+        let values = |count: usize| {
+            format!("INSERT INTO t VALUES {}", vec!["(1)"; count].join(", "))
+        };
+
+        let input = values(u16::MAX as usize);
+        let bytecode = create_codegen(&input).generate_bytecode().unwrap();
+        assert_eq!(
+            bytecode[..9],
+            [0x02, 0x09, 0x01, b't', 0x00, 0x00, 0x01, 0xFF, 0xFF]
+        );
+
+        let input = values(u16::MAX as usize + 1);
+        let error = create_codegen(&input).generate_bytecode().unwrap_err();
+
+        assert_eq!(error.kind, ErrorKind::Unsupported);
+        assert_eq!(error.span, Span::new(0, input.len(), 1, 1));
+        assert_eq!(error.message, "list contains more than 65535 items");
+    }
+
+    #[test]
+    fn test_codegen_syntax_error() {
+        let mut codegen = create_codegen("CREATE INDEX i;");
+        let error = codegen.generate_bytecode().unwrap_err();
+
+        assert_eq!(error.kind, ErrorKind::Syntax);
+        assert_eq!(error.span.column, 8);
     }
 }
@@ -0,0 +1,52 @@
+// Project name: TorusSQL.
+// Description: Relational database management system.
+// Licence: GPL-3.0.
+// Author: Alexander (@alkuzin).
+
+//! TorusSQL vendor-specific commands code generation declarations.
+
+use super::language_type_to_bytecode;
+use crate::compiler::codegen::{Bytecode, push_name, statement_to_bytecode};
+use crate::compiler::parser::ast::{LanguageType, Statement};
+use torussql_sdk::error::Result;
+
+/// Generate bytecode for inner virtual machine.
+///
+/// # Parameters
+/// - `bytecode`  - given bytecode to store.
+/// - `statement` - given SQL statement.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - in case of failure.
+pub fn generate_bytecode(
+    bytecode: &mut Bytecode,
+    statement: &Statement,
+) -> Result<()> {
+    // Bytecode header.
+    bytecode.push(language_type_to_bytecode(LanguageType::Vendor));
+    bytecode.push(statement_to_bytecode(statement));
+
+    // Handle different types of SQL statements.
+    match statement {
+        Statement::UseDatabase { name } => {
+            generate_use_database(bytecode, name)
+        }
+        _ => unreachable!("not a vendor statement: {statement:?}"),
+    }
+}
+
+/// Generate bytecode USE statement.
+///
+/// # Parameters
+/// - `bytecode` - given bytecode to store.
+/// - `name`     - given database name.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - in case of failure.
+fn generate_use_database(bytecode: &mut Bytecode, name: &str) -> Result<()> {
+    push_name(bytecode, name)?;
+
+    Ok(())
+}
@@ -0,0 +1,766 @@
+// Project name: TorusSQL.
+// Description: Relational database management system.
+// Licence: GPL-3.0.
+// Author: Alexander (@alkuzin).
+
+//! SQL keywords related declarations.
+//!
+//! Keyword set contains SQL:2016 reserved & non-reserved words (except
+//! embedded SQL host language names) and TorusSQL vendor keywords.
+//! Only words that are ambiguous in TorusSQL grammar are reserved, other
+//! keywords can still be used as table, column or function names.
+
+use std::{
+    cmp::Ordering,
+    convert::TryFrom,
+    fmt::{Display, Formatter},
+};
+
+/// Declare SQL keywords enumeration and keywords lookup table.
+///
+/// Keywords must be listed in ascending order of their text.
+macro_rules! keywords {
+    ($($variant:ident => ($text:literal, $reserved:literal)),* $(,)?) => {
+        /// SQL keywords enumeration.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum Keyword {
+            $($variant),*
+        }
+
+        /// SQL keywords lookup table sorted by keyword text.
+        static KEYWORDS: &[(&str, Keyword)] = &[
+            $(($text, Keyword::$variant)),*
+        ];
+
+        impl Keyword {
+            /// Get SQL keyword text.
+            ///
+            /// # Returns
+            /// - Uppercase keyword text.
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    $(Keyword::$variant => $text),*
+                }
+            }
+
+            /// Check whether SQL keyword is reserved.
+            ///
+            /// # Returns
+            /// - `true`  - if keyword can't be used as a name.
+            /// - `false` - otherwise.
+            pub const fn is_reserved(&self) -> bool {
+                match self {
+                    $(Keyword::$variant => $reserved),*
+                }
+            }
+        }
+    };
+}
+
+keywords! {
+    Abs => ("ABS", false),
+    Absolute => ("ABSOLUTE", false),
+    Acos => ("ACOS", false),
+    Action => ("ACTION", false),
+    Add => ("ADD", false),
+    Admin => ("ADMIN", false),
+    After => ("AFTER", false),
+    All => ("ALL", true),
+    Allocate => ("ALLOCATE", false),
+    Alter => ("ALTER", true),
+    Always => ("ALWAYS", false),
+    And => ("AND", true),
+    Any => ("ANY", true),
+    Are => ("ARE", false),
+    Array => ("ARRAY", true),
+    ArrayAgg => ("ARRAY_AGG", false),
+    ArrayMaxCardinality => ("ARRAY_MAX_CARDINALITY", false),
+    As => ("AS", true),
+    Asc => ("ASC", false),
+    Asensitive => ("ASENSITIVE", false),
+    Asin => ("ASIN", false),
+    Assertion => ("ASSERTION", false),
+    Assignment => ("ASSIGNMENT", false),
+    Asymmetric => ("ASYMMETRIC", true),
+    At => ("AT", false),
+    Atan => ("ATAN", false),
+    Atomic => ("ATOMIC", false),
+    Attribute => ("ATTRIBUTE", false),
+    Attributes => ("ATTRIBUTES", false),
+    Authorization => ("AUTHORIZATION", false),
+    Avg => ("AVG", false),
+    Before => ("BEFORE", false),
+    Begin => ("BEGIN", false),
+    BeginFrame => ("BEGIN_FRAME", false),
+    BeginPartition => ("BEGIN_PARTITION", false),
+    Bernoulli => ("BERNOULLI", false),
+    Between => ("BETWEEN", true),
+    Bigint => ("BIGINT", false),
+    Binary => ("BINARY", false),
+    Blob => ("BLOB", false),
+    Boolean => ("BOOLEAN", false),
+    Both => ("BOTH", true),
+    Breadth => ("BREADTH", false),
+    By => ("BY", true),
+    Call => ("CALL", false),
+    Called => ("CALLED", false),
+    Cardinality => ("CARDINALITY", false),
+    Cascade => ("CASCADE", false),
+    Cascaded => ("CASCADED", false),
+    Case => ("CASE", true),
+    Cast => ("CAST", true),
+    Catalog => ("CATALOG", false),
+    CatalogName => ("CATALOG_NAME", false),
+    Ceil => ("CEIL", false),
+    Ceiling => ("CEILING", false),
+    Chain => ("CHAIN", false),
+    Chaining => ("CHAINING", false),
+    Char => ("CHAR", false),
+    Character => ("CHARACTER", false),
+    Characteristics => ("CHARACTERISTICS", false),
+    Characters => ("CHARACTERS", false),
+    CharacterLength => ("CHARACTER_LENGTH", false),
+    CharacterSetCatalog => ("CHARACTER_SET_CATALOG", false),
+    CharacterSetName => ("CHARACTER_SET_NAME", false),
+    CharacterSetSchema => ("CHARACTER_SET_SCHEMA", false),
+    CharLength => ("CHAR_LENGTH", false),
+    Check => ("CHECK", true),
+    Classifier => ("CLASSIFIER", false),
+    ClassOrigin => ("CLASS_ORIGIN", false),
+    Clob => ("CLOB", false),
+    Close => ("CLOSE", false),
+    Coalesce => ("COALESCE", false),
+    Collate => ("COLLATE", true),
+    Collation => ("COLLATION", false),
+    CollationCatalog => ("COLLATION_CATALOG", false),
+    CollationName => ("COLLATION_NAME", false),
+    CollationSchema => ("COLLATION_SCHEMA", false),
+    Collect => ("COLLECT", false),
+    Column => ("COLUMN", true),
+    Columns => ("COLUMNS", false),
+    ColumnName => ("COLUMN_NAME", false),
+    CommandFunction => ("COMMAND_FUNCTION", false),
+    CommandFunctionCode => ("COMMAND_FUNCTION_CODE", false),
+    Commit => ("COMMIT", false),
+    Committed => ("COMMITTED", false),
+    Condition => ("CONDITION", false),
+    Conditional => ("CONDITIONAL", false),
+    ConditionNumber => ("CONDITION_NUMBER", false),
+    Connect => ("CONNECT", false),
+    Connection => ("CONNECTION", false),
+    ConnectionName => ("CONNECTION_NAME", false),
+    Constraint => ("CONSTRAINT", true),
+    Constraints => ("CONSTRAINTS", false),
+    ConstraintCatalog => ("CONSTRAINT_CATALOG", false),
+    ConstraintName => ("CONSTRAINT_NAME", false),
+    ConstraintSchema => ("CONSTRAINT_SCHEMA", false),
+    Constructor => ("CONSTRUCTOR", false),
+    Contains => ("CONTAINS", false),
+    Continue => ("CONTINUE", false),
+    Convert => ("CONVERT", false),
+    Copy => ("COPY", false),
+    Corr => ("CORR", false),
+    Corresponding => ("CORRESPONDING", false),
+    Cos => ("COS", false),
+    Cosh => ("COSH", false),
+    Count => ("COUNT", false),
+    CovarPop => ("COVAR_POP", false),
+    CovarSamp => ("COVAR_SAMP", false),
+    Create => ("CREATE", true),
+    Cross => ("CROSS", true),
+    Cube => ("CUBE", false),
+    CumeDist => ("CUME_DIST", false),
+    Current => ("CURRENT", false),
+    CurrentCatalog => ("CURRENT_CATALOG", true),
+    CurrentDate => ("CURRENT_DATE", true),
+    CurrentDefaultTransformGroup => ("CURRENT_DEFAULT_TRANSFORM_GROUP", false),
+    CurrentPath => ("CURRENT_PATH", false),
+    CurrentRole => ("CURRENT_ROLE", true),
+    CurrentRow => ("CURRENT_ROW", false),
+    CurrentSchema => ("CURRENT_SCHEMA", true),
+    CurrentTime => ("CURRENT_TIME", true),
+    CurrentTimestamp => ("CURRENT_TIMESTAMP", true),
+    CurrentTransformGroupForType => ("CURRENT_TRANSFORM_GROUP_FOR_TYPE", false),
+    CurrentUser => ("CURRENT_USER", true),
+    Cursor => ("CURSOR", false),
+    CursorName => ("CURSOR_NAME", false),
+    Cycle => ("CYCLE", false),
+    Data => ("DATA", false),
+    Database => ("DATABASE", false),
+    Date => ("DATE", false),
+    DatetimeIntervalCode => ("DATETIME_INTERVAL_CODE", false),
+    DatetimeIntervalPrecision => ("DATETIME_INTERVAL_PRECISION", false),
+    Day => ("DAY", false),
+    Deallocate => ("DEALLOCATE", false),
+    Dec => ("DEC", false),
+    Decfloat => ("DECFLOAT", false),
+    Decimal => ("DECIMAL", false),
+    Declare => ("DECLARE", false),
+    Default => ("DEFAULT", true),
+    Defaults => ("DEFAULTS", false),
+    Deferrable => ("DEFERRABLE", false),
+    Deferred => ("DEFERRED", false),
+    Define => ("DEFINE", false),
+    Defined => ("DEFINED", false),
+    Definer => ("DEFINER", false),
+    Degree => ("DEGREE", false),
+    Delete => ("DELETE", true),
+    DenseRank => ("DENSE_RANK", false),
+    Depth => ("DEPTH", false),
+    Deref => ("DEREF", false),
+    Derived => ("DERIVED", false),
+    Desc => ("DESC", false),
+    Describe => ("DESCRIBE", false),
+    Descriptor => ("DESCRIPTOR", false),
+    Deterministic => ("DETERMINISTIC", false),
+    Diagnostics => ("DIAGNOSTICS", false),
+    Disconnect => ("DISCONNECT", false),
+    Dispatch => ("DISPATCH", false),
+    Distinct => ("DISTINCT", true),
+    Domain => ("DOMAIN", false),
+    Double => ("DOUBLE", false),
+    Drop => ("DROP", true),
+    Dynamic => ("DYNAMIC", false),
+    DynamicFunction => ("DYNAMIC_FUNCTION", false),
+    DynamicFunctionCode => ("DYNAMIC_FUNCTION_CODE", false),
+    Each => ("EACH", false),
+    Element => ("ELEMENT", false),
+    Else => ("ELSE", true),
+    Empty => ("EMPTY", false),
+    Encoding => ("ENCODING", false),
+    End => ("END", true),
+    EndFrame => ("END_FRAME", false),
+    EndPartition => ("END_PARTITION", false),
+    Enforced => ("ENFORCED", false),
+    Equals => ("EQUALS", false),
+    Error => ("ERROR", false),
+    Escape => ("ESCAPE", false),
+    Every => ("EVERY", false),
+    Except => ("EXCEPT", true),
+    Exclude => ("EXCLUDE", false),
+    Excluding => ("EXCLUDING", false),
+    Exec => ("EXEC", false),
+    Execute => ("EXECUTE", false),
+    Exists => ("EXISTS", true),
+    Exp => ("EXP", false),
+    Expression => ("EXPRESSION", false),
+    External => ("EXTERNAL", false),
+    Extract => ("EXTRACT", false),
+    False => ("FALSE", true),
+    Fetch => ("FETCH", true),
+    Filter => ("FILTER", false),
+    Final => ("FINAL", false),
+    Finish => ("FINISH", false),
+    First => ("FIRST", false),
+    FirstValue => ("FIRST_VALUE", false),
+    Flag => ("FLAG", false),
+    Float => ("FLOAT", false),
+    Floor => ("FLOOR", false),
+    Following => ("FOLLOWING", false),
+    For => ("FOR", true),
+    Foreign => ("FOREIGN", true),
+    Format => ("FORMAT", false),
+    Found => ("FOUND", false),
+    FrameRow => ("FRAME_ROW", false),
+    Free => ("FREE", false),
+    From => ("FROM", true),
+    Fulfill => ("FULFILL", false),
+    Full => ("FULL", true),
+    Function => ("FUNCTION", false),
+    Fusion => ("FUSION", false),
+    General => ("GENERAL", false),
+    Generated => ("GENERATED", false),
+    Get => ("GET", false),
+    Global => ("GLOBAL", false),
+    Go => ("GO", false),
+    Goto => ("GOTO", false),
+    Grant => ("GRANT", true),
+    Granted => ("GRANTED", false),
+    Group => ("GROUP", true),
+    Grouping => ("GROUPING", false),
+    Groups => ("GROUPS", false),
+    Having => ("HAVING", true),
+    Hierarchy => ("HIERARCHY", false),
+    Hold => ("HOLD", false),
+    Hour => ("HOUR", false),
+    Identity => ("IDENTITY", false),
+    If => ("IF", false),
+    Ignore => ("IGNORE", false),
+    Ilike => ("ILIKE", true),
+    Immediate => ("IMMEDIATE", false),
+    Immediately => ("IMMEDIATELY", false),
+    Implementation => ("IMPLEMENTATION", false),
+    In => ("IN", true),
+    Including => ("INCLUDING", false),
+    Increment => ("INCREMENT", false),
+    Indicator => ("INDICATOR", false),
+    Initial => ("INITIAL", false),
+    Initially => ("INITIALLY", false),
+    Inner => ("INNER", true),
+    Inout => ("INOUT", false),
+    Input => ("INPUT", false),
+    Insensitive => ("INSENSITIVE", false),
+    Insert => ("INSERT", true),
+    Instance => ("INSTANCE", false),
+    Instantiable => ("INSTANTIABLE", false),
+    Instead => ("INSTEAD", false),
+    Int => ("INT", false),
+    Integer => ("INTEGER", false),
+    Intersect => ("INTERSECT", true),
+    Intersection => ("INTERSECTION", false),
+    Interval => ("INTERVAL", false),
+    Into => ("INTO", true),
+    Invoker => ("INVOKER", false),
+    Is => ("IS", true),
+    Isolation => ("ISOLATION", false),
+    Join => ("JOIN", true),
+    JsonArray => ("JSON_ARRAY", false),
+    JsonArrayagg => ("JSON_ARRAYAGG", false),
+    JsonExists => ("JSON_EXISTS", false),
+    JsonObject => ("JSON_OBJECT", false),
+    JsonObjectagg => ("JSON_OBJECTAGG", false),
+    JsonQuery => ("JSON_QUERY", false),
+    JsonTable => ("JSON_TABLE", false),
+    JsonTablePrimitive => ("JSON_TABLE_PRIMITIVE", false),
+    JsonValue => ("JSON_VALUE", false),
+    Keep => ("KEEP", false),
+    Key => ("KEY", false),
+    Keys => ("KEYS", false),
+    KeyMember => ("KEY_MEMBER", false),
+    KeyType => ("KEY_TYPE", false),
+    Lag => ("LAG", false),
+    Language => ("LANGUAGE", false),
+    Large => ("LARGE", false),
+    Last => ("LAST", false),
+    LastValue => ("LAST_VALUE", false),
+    Lateral => ("LATERAL", true),
+    Lead => ("LEAD", false),
+    Leading => ("LEADING", true),
+    Left => ("LEFT", true),
+    Length => ("LENGTH", false),
+    Level => ("LEVEL", false),
+    Like => ("LIKE", true),
+    LikeRegex => ("LIKE_REGEX", false),
+    Limit => ("LIMIT", true),
+    Listagg => ("LISTAGG", false),
+    Ln => ("LN", false),
+    Local => ("LOCAL", false),
+    Localtime => ("LOCALTIME", true),
+    Localtimestamp => ("LOCALTIMESTAMP", true),
+    Locator => ("LOCATOR", false),
+    Log => ("LOG", false),
+    Log10 => ("LOG10", false),
+    Lower => ("LOWER", false),
+    Map => ("MAP", false),
+    Match => ("MATCH", false),
+    Matched => ("MATCHED", false),
+    Matches => ("MATCHES", false),
+    MatchNumber => ("MATCH_NUMBER", false),
+    MatchRecognize => ("MATCH_RECOGNIZE", false),
+    Max => ("MAX", false),
+    Maxvalue => ("MAXVALUE", false),
+    Measures => ("MEASURES", false),
+    Member => ("MEMBER", false),
+    Merge => ("MERGE", false),
+    MessageLength => ("MESSAGE_LENGTH", false),
+    MessageOctetLength => ("MESSAGE_OCTET_LENGTH", false),
+    MessageText => ("MESSAGE_TEXT", false),
+    Method => ("METHOD", false),
+    Min => ("MIN", false),
+    Minute => ("MINUTE", false),
+    Minvalue => ("MINVALUE", false),
+    Mod => ("MOD", false),
+    Modifies => ("MODIFIES", false),
+    Module => ("MODULE", false),
+    Month => ("MONTH", false),
+    More => ("MORE", false),
+    Multiset => ("MULTISET", false),
+    Name => ("NAME", false),
+    Names => ("NAMES", false),
+    National => ("NATIONAL", false),
+    Natural => ("NATURAL", true),
+    Nchar => ("NCHAR", false),
+    Nclob => ("NCLOB", false),
+    Nested => ("NESTED", false),
+    Nesting => ("NESTING", false),
+    New => ("NEW", false),
+    Next => ("NEXT", false),
+    Nfc => ("NFC", false),
+    Nfd => ("NFD", false),
+    Nfkc => ("NFKC", false),
+    Nfkd => ("NFKD", false),
+    No => ("NO", false),
+    None => ("NONE", false),
+    Normalize => ("NORMALIZE", false),
+    Normalized => ("NORMALIZED", false),
+    Not => ("NOT", true),
+    NthValue => ("NTH_VALUE", false),
+    Ntile => ("NTILE", false),
+    Null => ("NULL", true),
+    Nullable => ("NULLABLE", false),
+    Nullif => ("NULLIF", false),
+    Nulls => ("NULLS", false),
+    NullOrdering => ("NULL_ORDERING", false),
+    Number => ("NUMBER", false),
+    Numeric => ("NUMERIC", false),
+    Object => ("OBJECT", false),
+    Occurrence => ("OCCURRENCE", false),
+    OccurrencesRegex => ("OCCURRENCES_REGEX", false),
+    Octets => ("OCTETS", false),
+    OctetLength => ("OCTET_LENGTH", false),
+    Of => ("OF", false),
+    Offset => ("OFFSET", true),
+    Old => ("OLD", false),
+    Omit => ("OMIT", false),
+    On => ("ON", true),
+    One => ("ONE", false),
+    Only => ("ONLY", true),
+    Open => ("OPEN", false),
+    Option => ("OPTION", false),
+    Options => ("OPTIONS", false),
+    Or => ("OR", true),
+    Order => ("ORDER", true),
+    Ordering => ("ORDERING", false),
+    Ordinality => ("ORDINALITY", false),
+    Others => ("OTHERS", false),
+    Out => ("OUT", false),
+    Outer => ("OUTER", true),
+    Output => ("OUTPUT", false),
+    Over => ("OVER", true),
+    Overflow => ("OVERFLOW", false),
+    Overlaps => ("OVERLAPS", false),
+    Overlay => ("OVERLAY", false),
+    Overriding => ("OVERRIDING", false),
+    Pad => ("PAD", false),
+    Parameter => ("PARAMETER", false),
+    ParameterMode => ("PARAMETER_MODE", false),
+    ParameterName => ("PARAMETER_NAME", false),
+    ParameterOrdinalPosition => ("PARAMETER_ORDINAL_POSITION", false),
+    ParameterSpecificCatalog => ("PARAMETER_SPECIFIC_CATALOG", false),
+    ParameterSpecificName => ("PARAMETER_SPECIFIC_NAME", false),
+    ParameterSpecificSchema => ("PARAMETER_SPECIFIC_SCHEMA", false),
+    Partial => ("PARTIAL", false),
+    Partition => ("PARTITION", false),
+    Pass => ("PASS", false),
+    Passing => ("PASSING", false),
+    Past => ("PAST", false),
+    Path => ("PATH", false),
+    Pattern => ("PATTERN", false),
+    Per => ("PER", false),
+    Percent => ("PERCENT", false),
+    PercentileCont => ("PERCENTILE_CONT", false),
+    PercentileDisc => ("PERCENTILE_DISC", false),
+    PercentRank => ("PERCENT_RANK", false),
+    Period => ("PERIOD", false),
+    Placing => ("PLACING", false),
+    Plan => ("PLAN", false),
+    Portion => ("PORTION", false),
+    Position => ("POSITION", false),
+    PositionRegex => ("POSITION_REGEX", false),
+    Power => ("POWER", false),
+    Precedes => ("PRECEDES", false),
+    Preceding => ("PRECEDING", false),
+    Precision => ("PRECISION", false),
+    Prepare => ("PREPARE", false),
+    Preserve => ("PRESERVE", false),
+    Primary => ("PRIMARY", true),
+    Prior => ("PRIOR", false),
+    Private => ("PRIVATE", false),
+    Privileges => ("PRIVILEGES", false),
+    Procedure => ("PROCEDURE", false),
+    Prune => ("PRUNE", false),
+    Ptf => ("PTF", false),
+    Public => ("PUBLIC", false),
+    Quotes => ("QUOTES", false),
+    Range => ("RANGE", false),
+    Rank => ("RANK", false),
+    Read => ("READ", false),
+    Reads => ("READS", false),
+    Real => ("REAL", false),
+    Recursive => ("RECURSIVE", false),
+    Ref => ("REF", false),
+    References => ("REFERENCES", true),
+    Referencing => ("REFERENCING", false),
+    RegrAvgx => ("REGR_AVGX", false),
+    RegrAvgy => ("REGR_AVGY", false),
+    RegrCount => ("REGR_COUNT", false),
+    RegrIntercept => ("REGR_INTERCEPT", false),
+    RegrR2 => ("REGR_R2", false),
+    RegrSlope => ("REGR_SLOPE", false),
+    RegrSxx => ("REGR_SXX", false),
+    RegrSxy => ("REGR_SXY", false),
+    RegrSyy => ("REGR_SYY", false),
+    Relative => ("RELATIVE", false),
+    Release => ("RELEASE", false),
+    Rename => ("RENAME", false),
+    Repeatable => ("REPEATABLE", false),
+    Respect => ("RESPECT", false),
+    Restart => ("RESTART", false),
+    Restrict => ("RESTRICT", false),
+    Result => ("RESULT", false),
+    Return => ("RETURN", false),
+    ReturnedCardinality => ("RETURNED_CARDINALITY", false),
+    ReturnedLength => ("RETURNED_LENGTH", false),
+    ReturnedOctetLength => ("RETURNED_OCTET_LENGTH", false),
+    ReturnedSqlstate => ("RETURNED_SQLSTATE", false),
+    Returning => ("RETURNING", false),
+    Returns => ("RETURNS", false),
+    Revoke => ("REVOKE", false),
+    Right => ("RIGHT", true),
+    Role => ("ROLE", false),
+    Rollback => ("ROLLBACK", false),
+    Rollup => ("ROLLUP", false),
+    Routine => ("ROUTINE", false),
+    RoutineCatalog => ("ROUTINE_CATALOG", false),
+    RoutineName => ("ROUTINE_NAME", false),
+    RoutineSchema => ("ROUTINE_SCHEMA", false),
+    Row => ("ROW", false),
+    Rows => ("ROWS", false),
+    RowCount => ("ROW_COUNT", false),
+    RowNumber => ("ROW_NUMBER", false),
+    Running => ("RUNNING", false),
+    Savepoint => ("SAVEPOINT", false),
+    Scalar => ("SCALAR", false),
+    Scale => ("SCALE", false),
+    Schema => ("SCHEMA", false),
+    SchemaName => ("SCHEMA_NAME", false),
+    Scope => ("SCOPE", false),
+    ScopeCatalog => ("SCOPE_CATALOG", false),
+    ScopeName => ("SCOPE_NAME", false),
+    ScopeSchema => ("SCOPE_SCHEMA", false),
+    Scroll => ("SCROLL", false),
+    Search => ("SEARCH", false),
+    Second => ("SECOND", false),
+    Section => ("SECTION", false),
+    Security => ("SECURITY", false),
+    Seek => ("SEEK", false),
+    Select => ("SELECT", true),
+    Sensitive => ("SENSITIVE", false),
+    Sequence => ("SEQUENCE", false),
+    Serializable => ("SERIALIZABLE", false),
+    ServerName => ("SERVER_NAME", false),
+    Session => ("SESSION", false),
+    SessionUser => ("SESSION_USER", true),
+    Set => ("SET", true),
+    Sets => ("SETS", false),
+    Show => ("SHOW", false),
+    Similar => ("SIMILAR", true),
+    Simple => ("SIMPLE", false),
+    Sin => ("SIN", false),
+    Sinh => ("SINH", false),
+    Size => ("SIZE", false),
+    Skip => ("SKIP", false),
+    Smallint => ("SMALLINT", false),
+    Some => ("SOME", true),
+    Source => ("SOURCE", false),
+    Space => ("SPACE", false),
+    Specific => ("SPECIFIC", false),
+    Specifictype => ("SPECIFICTYPE", false),
+    SpecificName => ("SPECIFIC_NAME", false),
+    Sql => ("SQL", false),
+    Sqlexception => ("SQLEXCEPTION", false),
+    Sqlstate => ("SQLSTATE", false),
+    Sqlwarning => ("SQLWARNING", false),
+    Sqrt => ("SQRT", false),
+    Start => ("START", false),
+    State => ("STATE", false),
+    Statement => ("STATEMENT", false),
+    Static => ("STATIC", false),
+    StddevPop => ("STDDEV_POP", false),
+    StddevSamp => ("STDDEV_SAMP", false),
+    String => ("STRING", false),
+    Structure => ("STRUCTURE", false),
+    Style => ("STYLE", false),
+    SubclassOrigin => ("SUBCLASS_ORIGIN", false),
+    Submultiset => ("SUBMULTISET", false),
+    Subset => ("SUBSET", false),
+    Substring => ("SUBSTRING", false),
+    SubstringRegex => ("SUBSTRING_REGEX", false),
+    Succeeds => ("SUCCEEDS", false),
+    Sum => ("SUM", false),
+    Symmetric => ("SYMMETRIC", true),
+    System => ("SYSTEM", false),
+    SystemTime => ("SYSTEM_TIME", false),
+    SystemUser => ("SYSTEM_USER", false),
+    Table => ("TABLE", true),
+    Tablesample => ("TABLESAMPLE", false),
+    TableName => ("TABLE_NAME", false),
+    Tan => ("TAN", false),
+    Tanh => ("TANH", false),
+    Temporary => ("TEMPORARY", false),
+    Text => ("TEXT", false),
+    Then => ("THEN", true),
+    Through => ("THROUGH", false),
+    Ties => ("TIES", false),
+    Time => ("TIME", false),
+    Timestamp => ("TIMESTAMP", false),
+    TimezoneHour => ("TIMEZONE_HOUR", false),
+    TimezoneMinute => ("TIMEZONE_MINUTE", false),
+    To => ("TO", true),
+    TopLevelCount => ("TOP_LEVEL_COUNT", false),
+    Trailing => ("TRAILING", true),
+    Transaction => ("TRANSACTION", false),
+    TransactionsCommitted => ("TRANSACTIONS_COMMITTED", false),
+    TransactionsRolledBack => ("TRANSACTIONS_ROLLED_BACK", false),
+    TransactionActive => ("TRANSACTION_ACTIVE", false),
+    Transform => ("TRANSFORM", false),
+    Transforms => ("TRANSFORMS", false),
+    Translate => ("TRANSLATE", false),
+    TranslateRegex => ("TRANSLATE_REGEX", false),
+    Translation => ("TRANSLATION", false),
+    Treat => ("TREAT", false),
+    Trigger => ("TRIGGER", false),
+    TriggerCatalog => ("TRIGGER_CATALOG", false),
+    TriggerName => ("TRIGGER_NAME", false),
+    TriggerSchema => ("TRIGGER_SCHEMA", false),
+    Trim => ("TRIM", false),
+    TrimArray => ("TRIM_ARRAY", false),
+    True => ("TRUE", true),
+    Truncate => ("TRUNCATE", false),
+    Type => ("TYPE", false),
+    Uescape => ("UESCAPE", false),
+    Unbounded => ("UNBOUNDED", false),
+    Uncommitted => ("UNCOMMITTED", false),
+    Unconditional => ("UNCONDITIONAL", false),
+    Under => ("UNDER", false),
+    Union => ("UNION", true),
+    Unique => ("UNIQUE", true),
+    Unknown => ("UNKNOWN", false),
+    Unnamed => ("UNNAMED", false),
+    Unnest => ("UNNEST", false),
+    Update => ("UPDATE", true),
+    Upper => ("UPPER", false),
+    Usage => ("USAGE", false),
+    Use => ("USE", false),
+    User => ("USER", true),
+    UserDefinedTypeCatalog => ("USER_DEFINED_TYPE_CATALOG", false),
+    UserDefinedTypeCode => ("USER_DEFINED_TYPE_CODE", false),
+    UserDefinedTypeName => ("USER_DEFINED_TYPE_NAME", false),
+    UserDefinedTypeSchema => ("USER_DEFINED_TYPE_SCHEMA", false),
+    Using => ("USING", true),
+    Utf16 => ("UTF16", false),
+    Utf32 => ("UTF32", false),
+    Utf8 => ("UTF8", false),
+    Value => ("VALUE", false),
+    Values => ("VALUES", true),
+    ValueOf => ("VALUE_OF", false),
+    Varbinary => ("VARBINARY", false),
+    Varchar => ("VARCHAR", false),
+    Varying => ("VARYING", false),
+    VarPop => ("VAR_POP", false),
+    VarSamp => ("VAR_SAMP", false),
+    Versioning => ("VERSIONING", false),
+    View => ("VIEW", false),
+    When => ("WHEN", true),
+    Whenever => ("WHENEVER", false),
+    Where => ("WHERE", true),
+    WidthBucket => ("WIDTH_BUCKET", false),
+    Window => ("WINDOW", true),
+    With => ("WITH", true),
+    Within => ("WITHIN", false),
+    Without => ("WITHOUT", false),
+    Work => ("WORK", false),
+    Wrapper => ("WRAPPER", false),
+    Write => ("WRITE", false),
+    Year => ("YEAR", false),
+    Zone => ("ZONE", false),
+}
+
+impl Keyword {
+    /// Find SQL keyword by its text ignoring case.
+    ///
+    /// # Parameters
+    /// - `value` - given text to find.
+    ///
+    /// # Returns
+    /// - `SQL keyword` - in case of success.
+    /// - `None`        - otherwise.
+    pub fn lookup(value: &str) -> Option<Keyword> {
+        KEYWORDS
+            .binary_search_by(|(text, _)| compare_ignore_case(text, value))
+            .ok()
+            .map(|index| KEYWORDS[index].1)
+    }
+}
+
+/// Compare uppercase keyword text with given text ignoring case.
+///
+/// # Parameters
+/// - `keyword` - given uppercase keyword text.
+/// - `value`   - given text to compare with.
+///
+/// # Returns
+/// - Order of keyword relative to given text.
+#[inline(always)]
+fn compare_ignore_case(keyword: &str, value: &str) -> Ordering {
+    let value = value.bytes().map(|b| b.to_ascii_uppercase());
+    keyword.bytes().cmp(value)
+}
+
+impl TryFrom<&str> for Keyword {
+    // TODO: replace with TorusSQL error enum.
+    type Error = &'static str;
+
+    /// Try to convert string to SQL keyword.
+    ///
+    /// # Parameters
+    /// - `value` - given string value to convert.
+    ///
+    /// # Returns
+    /// - `SQL keyword` - in case of success.
+    /// - `Err`         - otherwise.
+    fn try_from(value: &str) -> Result<Self, &'static str> {
+        Keyword::lookup(value).ok_or("Not a keyword")
+    }
+}
+
+impl Display for Keyword {
+    /// Display SQL keyword.
+    ///
+    /// # Parameters
+    /// - `f` - given formatter.
+    ///
+    /// # Returns
+    /// - `OK`  - in case of success.
+    /// - `Err` - otherwise.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keywords_sorted() {
+        for pair in KEYWORDS.windows(2) {
+            assert!(pair[0].0 < pair[1].0, "{} >= {}", pair[0].0, pair[1].0);
+        }
+    }
+
+    #[test]
+    fn test_lookup() {
+        assert_eq!(Keyword::lookup("create"), Some(Keyword::Create));
+        assert_eq!(Keyword::lookup("DaTaBaSe"), Some(Keyword::Database));
+        assert_eq!(Keyword::lookup("CURRENT_DATE"), Some(Keyword::CurrentDate));
+        assert_eq!(Keyword::lookup("log10"), Some(Keyword::Log10));
+        assert_eq!(Keyword::lookup("users"), None);
+        assert_eq!(Keyword::lookup("créate"), None);
+        assert_eq!(Keyword::lookup(""), None);
+
+        for (text, keyword) in KEYWORDS {
+            assert_eq!(Keyword::lookup(text), Some(*keyword));
+            assert_eq!(keyword.as_str(), *text);
+        }
+    }
+
+    #[test]
+    fn test_reserved() {
+        assert!(Keyword::Select.is_reserved());
+        assert!(Keyword::Create.is_reserved());
+        assert!(!Keyword::Name.is_reserved());
+        assert!(!Keyword::Type.is_reserved());
+        assert!(!Keyword::Count.is_reserved());
+    }
+}
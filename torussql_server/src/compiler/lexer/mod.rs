@@ -5,16 +5,49 @@
 
 //! SQL lexer related declarations.
 
+pub mod keyword;
 pub mod token;
 
 use crate::log;
-use std::{iter::Peekable, str::Chars};
-use token::{Keyword, Token};
+use std::iter::FusedIterator;
+use token::{Keyword, Spanned, Token};
+use torussql_sdk::error::{ErrorKind, Result, Span, TorusError};
+use unicode_normalization::{UnicodeNormalization, char::is_combining_mark};
+
+/// Maximum length of identifier in bytes.
+pub const MAX_IDENTIFIER_LENGTH: usize = 64;
+
+/// Maximum magnitude of integer literal (sign is a separate token).
+pub const MAX_INTEGER_MAGNITUDE: u64 = i64::MIN.unsigned_abs();
+
+/// SQL lexer options struct.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LexerOptions {
+    /// Treat `"text"` as string literal instead of quoted identifier.
+    /// Kept for compatibility with scripts written before standard
+    /// SQL quoting was supported.
+    pub double_quoted_strings: bool,
+    /// Emit whitespace & comment tokens instead of skipping them.
+    /// Concatenation of all tokens text reproduces SQL code exactly.
+    pub preserve_trivia: bool,
+}
 
 /// Struct that converts SQL code into tokens.
 pub struct Lexer<'a> {
-    /// SQL code set of chars.
-    input: Peekable<Chars<'a>>,
+    /// SQL code.
+    input: &'a str,
+    /// Lexer options.
+    options: LexerOptions,
+    /// Byte offset of the current character.
+    offset: usize,
+    /// Line number of the current character.
+    line: usize,
+    /// Column number of the current character.
+    column: usize,
+    /// Position of the token being consumed.
+    span: Span,
+    /// Flag signaling that iteration over tokens is over.
+    finished: bool,
 }
 
 impl<'a> Lexer<'a> {
@@ -26,174 +59,751 @@ impl<'a> Lexer<'a> {
     /// # Returns
     /// - New `Lexer` object.
     pub fn new(input: &'a str) -> Self {
+        Self::with_options(input, LexerOptions::default())
+    }
+
+    /// Construct new `Lexer` object with custom options.
+    ///
+    /// # Parameters
+    /// - `input`   - given SQL code.
+    /// - `options` - given lexer options.
+    ///
+    /// # Returns
+    /// - New `Lexer` object.
+    pub fn with_options(input: &'a str, options: LexerOptions) -> Self {
         Self {
-            input: input.chars().peekable(),
+            input,
+            options,
+            offset: 0,
+            line: 1,
+            column: 1,
+            span: Span::new(0, 0, 1, 1),
+            finished: false,
         }
     }
 
+    /// Get lexer options.
+    ///
+    /// # Returns
+    /// - Lexer options.
+    #[inline(always)]
+    pub fn options(&self) -> LexerOptions {
+        self.options
+    }
+
     /// Get next token.
     ///
     /// # Returns
     /// - `SQL token`  - in case of success.
     /// - `Token::End` - in case of reaching end of SQL code.
-    /// - `None`       - in case of failure.
-    pub fn next_token(&mut self) -> Option<Token> {
-        // Skip whitespaces.
-        if let Some(current_char) = self.input.peek() {
-            if current_char.is_whitespace() {
-                self.skip_whitespace();
-            }
+    /// - `Err`        - in case of failure.
+    pub fn next_token(&mut self) -> Result<Spanned<Token>> {
+        if !self.options.preserve_trivia {
+            // Skip whitespaces & comments.
+            self.skip_whitespace_and_comments()?;
         }
 
+        self.span = self.current_position();
+
         // Handle characters.
-        if let Some(c) = self.input.peek() {
-            let token = match c {
-                c if c.is_alphabetic() => self.consume_keyword_or_ident(),
-                '"' => self.consume_string(),
-                _ => self.consume_symbol(),
-            };
+        let token = match self.peek() {
+            Some('x' | 'X') if self.peek_nth(1) == Some('\'') => {
+                self.consume_blob()
+            }
+            Some('b' | 'B') if self.peek_nth(1) == Some('\'') => {
+                self.consume_bit_string()
+            }
+            Some('e' | 'E') if self.peek_nth(1) == Some('\'') => {
+                self.consume_escape_string()
+            }
+            Some(c) if c.is_whitespace() => {
+                self.skip_while(char::is_whitespace);
+                Ok(Token::Whitespace(self.token_text().to_string()))
+            }
+            Some('-') if self.peek_nth(1) == Some('-') => {
+                self.consume_line_comment();
+                Ok(Token::Comment(self.token_text().to_string()))
+            }
+            Some('/') if self.peek_nth(1) == Some('*') => {
+                self.consume_block_comment()?;
+                Ok(Token::Comment(self.token_text().to_string()))
+            }
+            Some(c) if is_identifier_start(c) => {
+                self.consume_keyword_or_ident()
+            }
+            Some(c) if c.is_ascii_digit() => self.consume_number(),
+            Some('.')
+                if self.peek_nth(1).is_some_and(|c| c.is_ascii_digit()) =>
+            {
+                self.consume_number()
+            }
+            Some('\'') => self.consume_string(),
+            Some('"') if self.options.double_quoted_strings => {
+                let value = self.consume_quoted('"')?;
+                Ok(Token::String(value))
+            }
+            Some('"') => self.consume_quoted_identifier(),
+            Some(_) => self.consume_symbol(),
+            // End of SQL code was reached.
+            None => Ok(Token::End),
+        }?;
 
-            self.advance();
-            return token;
+        self.span.end = self.offset;
+        Ok(Spanned::new(token, self.span))
+    }
+
+    /// Skip space characters & comments.
+    ///
+    /// # Returns
+    ///  - `Ok`  - in case of success.
+    ///  - `Err` - in case of unterminated comment.
+    fn skip_whitespace_and_comments(&mut self) -> Result<()> {
+        loop {
+            self.skip_while(char::is_whitespace);
+            self.span = self.current_position();
+
+            match (self.peek(), self.peek_nth(1)) {
+                (Some('-'), Some('-')) => self.consume_line_comment(),
+                (Some('/'), Some('*')) => self.consume_block_comment()?,
+                _ => return Ok(()),
+            }
         }
+    }
 
-        // End of SQL code was reached.
-        Some(Token::End)
+    /// Get text of the token being consumed.
+    ///
+    /// # Returns
+    /// - SQL code fragment from token start to the current character.
+    #[inline(always)]
+    fn token_text(&self) -> &'a str {
+        &self.input[self.span.start..self.offset]
     }
 
-    /// Skip space characters.
-    fn skip_whitespace(&mut self) {
-        while self.input.peek().is_some()
-            && self.input.peek().unwrap().is_whitespace()
-        {
-            self.advance()
+    /// Consume line comment (`-- text`) up to the end of line.
+    fn consume_line_comment(&mut self) {
+        self.skip_while(|c| c != '\n');
+    }
+
+    /// Consume block comment (`/* text */`). Block comments can be nested.
+    ///
+    /// # Returns
+    ///  - `Ok`  - in case of success.
+    ///  - `Err` - in case of unterminated comment.
+    fn consume_block_comment(&mut self) -> Result<()> {
+        let mut depth = 0usize;
+
+        loop {
+            match (self.peek(), self.peek_nth(1)) {
+                (Some('/'), Some('*')) => {
+                    self.advance();
+                    self.advance();
+                    depth += 1;
+                }
+                (Some('*'), Some('/')) => {
+                    self.advance();
+                    self.advance();
+                    depth -= 1;
+
+                    if depth == 0 {
+                        return Ok(());
+                    }
+                }
+                (Some(_), _) => self.advance(),
+                (None, _) => {
+                    return Err(self.error("unterminated block comment"));
+                }
+            }
         }
     }
 
+    /// Get current character.
+    ///
+    /// # Returns
+    /// - Current character - if end of SQL code was not reached.
+    /// - `None`            - otherwise.
+    #[inline(always)]
+    fn peek(&self) -> Option<char> {
+        self.input[self.offset..].chars().next()
+    }
+
+    /// Get character located `n` characters after the current one.
+    ///
+    /// # Parameters
+    /// - `n` - given number of characters to look ahead.
+    ///
+    /// # Returns
+    /// - Found character - if end of SQL code was not reached.
+    /// - `None`          - otherwise.
+    #[inline(always)]
+    fn peek_nth(&self, n: usize) -> Option<char> {
+        self.input[self.offset..].chars().nth(n)
+    }
+
     /// Advance current character position.
     #[inline(always)]
     fn advance(&mut self) {
-        self.input.next();
+        if let Some(c) = self.peek() {
+            self.offset += c.len_utf8();
+
+            if c == '\n' {
+                self.line += 1;
+                self.column = 1;
+            } else {
+                self.column += 1;
+            }
+        }
+    }
+
+    /// Get position of the current character.
+    ///
+    /// # Returns
+    /// - Empty span pointing at the current character.
+    #[inline(always)]
+    fn current_position(&self) -> Span {
+        Span::new(self.offset, self.offset, self.line, self.column)
+    }
+
+    /// Construct lexical error for the current token.
+    ///
+    /// # Parameters
+    /// - `message` - given error description.
+    ///
+    /// # Returns
+    /// - New lexical error.
+    fn error(&self, message: impl Into<String>) -> TorusError {
+        let mut span = self.span;
+        span.end = self.offset;
+
+        TorusError::new(ErrorKind::Lexical, span, message)
     }
 
     /// Consume keyword or ident token.
     ///
     /// # Returns
     ///  - `SQL token` - in case of success.
-    ///  - `None`      - otherwise.
-    fn consume_keyword_or_ident(&mut self) -> Option<Token> {
-        let mut value = String::new();
+    ///  - `Err`       - otherwise.
+    fn consume_keyword_or_ident(&mut self) -> Result<Token> {
+        let start = self.offset;
 
         // Extract keyword/ident from input.
-        while let Some(&c) = self.input.peek() {
-            if c.is_alphabetic() {
-                value.push(c);
-                self.advance()
-            } else {
-                break;
-            }
-        }
-
-        // Handle empty string.
-        if value.is_empty() {
-            log::error!("Can't convert to token");
-            return None;
-        }
+        self.skip_while(is_identifier_part);
+        let value = &self.input[start..self.offset];
 
         log::debug!("Found value: \"{}\"", value);
 
         // Try to convert to SQL keyword.
-        let result = Keyword::try_from(value.as_str());
+        let result = Keyword::try_from(value);
 
         match result {
             Ok(keyword) => {
                 log::debug!("Found keyword: {}", keyword);
-                Some(Token::Keyword(keyword))
+                Ok(Token::Keyword(keyword))
             }
             Err(_) => {
-                // Convert to ident token.
-                Some(Token::String(value))
+                // Fold unquoted identifier to canonical case.
+                let value = self.normalize_identifier(&value.to_lowercase())?;
+                Ok(Token::Identifier(value))
             }
         }
     }
 
-    /// Consume string literal.
+    /// Convert identifier to Unicode NFC form and check its length.
+    ///
+    /// # Parameters
+    /// - `value` - given identifier to normalize.
+    ///
+    /// # Returns
+    ///  - Normalized identifier - in case of success.
+    ///  - `Err`                 - otherwise.
+    fn normalize_identifier(&self, value: &str) -> Result<String> {
+        let value: String = value.nfc().collect();
+
+        if value.len() > MAX_IDENTIFIER_LENGTH {
+            let message = format!(
+                "identifier is longer than {MAX_IDENTIFIER_LENGTH} bytes"
+            );
+            return Err(self.error(message));
+        }
+
+        Ok(value)
+    }
+
+    /// Consume numeric literal.
     ///
     /// # Returns
     ///  - `SQL token` - in case of success.
-    ///  - `None`      - otherwise.
-    fn consume_string(&mut self) -> Option<Token> {
-        // Skip '"' symbol.
+    ///  - `Err`       - otherwise.
+    fn consume_number(&mut self) -> Result<Token> {
+        let start = self.offset;
+
+        // Handle hexadecimal integer.
+        if self.peek() == Some('0')
+            && matches!(self.peek_nth(1), Some('x' | 'X'))
+        {
+            self.advance();
+            self.advance();
+
+            let digits_start = self.offset;
+            self.skip_while(|c| c.is_ascii_hexdigit());
+            let digits = &self.input[digits_start..self.offset];
+
+            if digits.is_empty() {
+                return Err(self.error("missing hexadecimal digits"));
+            }
+
+            self.check_number_end()?;
+
+            return match u64::from_str_radix(digits, 16) {
+                Ok(value) if value <= MAX_INTEGER_MAGNITUDE => {
+                    Ok(Token::Integer(value))
+                }
+                _ => Err(self.error("integer literal is out of range")),
+            };
+        }
+
+        let mut is_float = false;
+        self.skip_while(|c| c.is_ascii_digit());
+
+        // Handle fractional part.
+        if self.peek() == Some('.') {
+            is_float = true;
+            self.advance();
+            self.skip_while(|c| c.is_ascii_digit());
+        }
+
+        // Handle exponent.
+        if matches!(self.peek(), Some('e' | 'E')) {
+            is_float = true;
+            self.advance();
+
+            if matches!(self.peek(), Some('+' | '-')) {
+                self.advance();
+            }
+
+            if !self.peek().is_some_and(|c| c.is_ascii_digit()) {
+                return Err(self.error("missing exponent digits"));
+            }
+
+            self.skip_while(|c| c.is_ascii_digit());
+        }
+
+        self.check_number_end()?;
+
+        let value = &self.input[start..self.offset];
+        log::debug!("Found number: {value}");
+
+        if is_float {
+            return match value.parse::<f64>() {
+                Ok(value) if value.is_finite() => Ok(Token::Float(value)),
+                _ => Err(self.error("float literal is out of range")),
+            };
+        }
+
+        match value.parse::<u64>() {
+            Ok(value) if value <= MAX_INTEGER_MAGNITUDE => {
+                Ok(Token::Integer(value))
+            }
+            _ => Err(self.error("integer literal is out of range")),
+        }
+    }
+
+    /// Check that numeric literal is not followed by identifier characters.
+    ///
+    /// # Returns
+    ///  - `Ok`  - in case of success.
+    ///  - `Err` - otherwise.
+    fn check_number_end(&mut self) -> Result<()> {
+        if self.peek().is_some_and(is_identifier_part) {
+            self.skip_while(is_identifier_part);
+            return Err(self.error("trailing characters after numeric literal"));
+        }
+
+        Ok(())
+    }
+
+    /// Skip characters while they satisfy given predicate.
+    ///
+    /// # Parameters
+    /// - `predicate` - given function to check characters.
+    fn skip_while(&mut self, predicate: impl Fn(char) -> bool) {
+        while self.peek().is_some_and(&predicate) {
+            self.advance();
+        }
+    }
+
+    /// Consume string literal (`'text'`).
+    ///
+    /// # Returns
+    ///  - `SQL token` - in case of success.
+    ///  - `Err`       - otherwise.
+    fn consume_string(&mut self) -> Result<Token> {
+        let value = self.consume_quoted('\'')?;
+
+        log::debug!("Found literal string: '{value}'");
+        Ok(Token::String(value))
+    }
+
+    /// Consume blob literal (`X'DEADBEEF'`).
+    ///
+    /// # Returns
+    ///  - `SQL token` - in case of success.
+    ///  - `Err`       - otherwise.
+    fn consume_blob(&mut self) -> Result<Token> {
+        // Skip 'X' prefix.
+        self.advance();
+        let value = self.consume_quoted('\'')?;
+
+        if !value.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(self.error("invalid hexadecimal digit in blob literal"));
+        }
+
+        if value.len() % 2 != 0 {
+            return Err(self.error("odd number of digits in blob literal"));
+        }
+
+        let bytes = (0..value.len())
+            .step_by(2)
+            .filter_map(|i| u8::from_str_radix(&value[i..i + 2], 16).ok())
+            .collect();
+
+        log::debug!("Found blob literal: X'{value}'");
+        Ok(Token::Blob(bytes))
+    }
+
+    /// Consume bit string literal (`B'1010'`).
+    ///
+    /// # Returns
+    ///  - `SQL token` - in case of success.
+    ///  - `Err`       - otherwise.
+    fn consume_bit_string(&mut self) -> Result<Token> {
+        // Skip 'B' prefix.
+        self.advance();
+        let value = self.consume_quoted('\'')?;
+
+        if !value.chars().all(|c| c == '0' || c == '1') {
+            return Err(
+                self.error("invalid binary digit in bit string literal")
+            );
+        }
+
+        log::debug!("Found bit string literal: B'{value}'");
+        Ok(Token::BitString(value.chars().map(|c| c == '1').collect()))
+    }
+
+    /// Consume string literal with C-style escapes (`E'line\n'`).
+    ///
+    /// # Returns
+    ///  - `SQL token` - in case of success.
+    ///  - `Err`       - otherwise.
+    fn consume_escape_string(&mut self) -> Result<Token> {
+        // Skip 'E' prefix & opening quote.
+        self.advance();
         self.advance();
         let mut value = String::new();
 
-        while let Some(c) = self.input.peek() {
-            if *c == '"' {
-                // Consume the closing quote.
-                self.advance();
-                break;
+        loop {
+            let Some(c) = self.peek() else {
+                return Err(self.error("unterminated string literal"));
+            };
+
+            self.advance();
+
+            match c {
+                // Handle escaped quote.
+                '\'' if self.peek() == Some('\'') => {
+                    self.advance();
+                    value.push('\'');
+                }
+                '\'' => break,
+                '\\' => value.push(self.consume_escape()?),
+                _ => value.push(c),
             }
+        }
+
+        log::debug!("Found escape string: {value:?}");
+        Ok(Token::String(value))
+    }
+
+    /// Consume escape sequence following backslash.
+    ///
+    /// # Returns
+    ///  - Escaped character - in case of success.
+    ///  - `Err`             - otherwise.
+    fn consume_escape(&mut self) -> Result<char> {
+        let c = match self.peek() {
+            Some('0'..='7') => return self.consume_code_point(8, 1, 3),
+            Some(c) => c,
+            None => return Err(self.error("unterminated string literal")),
+        };
+
+        self.advance();
+
+        match c {
+            'n' => Ok('\n'),
+            't' => Ok('\t'),
+            'r' => Ok('\r'),
+            'b' => Ok('\u{8}'),
+            'f' => Ok('\u{c}'),
+            '\\' | '\'' | '"' => Ok(c),
+            'x' => self.consume_code_point(16, 1, 2),
+            'u' => self.consume_code_point(16, 4, 4),
+            'U' => self.consume_code_point(16, 8, 8),
+            _ => Err(self.error(format!("invalid escape sequence '\\{c}'"))),
+        }
+    }
 
-            value.push(*c);
+    /// Consume numeric code of escaped character.
+    ///
+    /// # Parameters
+    /// - `radix` - given code radix.
+    /// - `min`   - given minimal number of digits.
+    /// - `max`   - given maximal number of digits.
+    ///
+    /// # Returns
+    ///  - Escaped character - in case of success.
+    ///  - `Err`             - otherwise.
+    fn consume_code_point(
+        &mut self,
+        radix: u32,
+        min: usize,
+        max: usize,
+    ) -> Result<char> {
+        let start = self.offset;
+        let mut count = 0;
+
+        while count < max && self.peek().is_some_and(|c| c.is_digit(radix)) {
             self.advance();
+            count += 1;
         }
 
-        if !value.is_empty() {
-            log::debug!("Found literal string: \"{value}\"");
-            return Some(Token::String(value));
+        let digits = &self.input[start..self.offset];
+
+        u32::from_str_radix(digits, radix)
+            .ok()
+            .filter(|_| count >= min)
+            .filter(|&code| code != 0)
+            .and_then(char::from_u32)
+            .ok_or_else(|| self.error("invalid escape sequence"))
+    }
+
+    /// Consume case-preserving quoted identifier (`"Name"`).
+    ///
+    /// # Returns
+    ///  - `SQL token` - in case of success.
+    ///  - `Err`       - otherwise.
+    fn consume_quoted_identifier(&mut self) -> Result<Token> {
+        let value = self.consume_quoted('"')?;
+
+        if value.is_empty() {
+            return Err(self.error("zero-length quoted identifier"));
         }
 
-        None
+        let value = self.normalize_identifier(&value)?;
+
+        log::debug!("Found quoted identifier: \"{value}\"");
+        Ok(Token::QuotedIdentifier(value))
+    }
+
+    /// Consume text enclosed in quotes. Doubled quote inside text
+    /// stands for a single quote character.
+    ///
+    /// # Parameters
+    /// - `quote` - given quote character.
+    ///
+    /// # Returns
+    ///  - Text without enclosing quotes - in case of success.
+    ///  - `Err`                         - otherwise.
+    fn consume_quoted(&mut self, quote: char) -> Result<String> {
+        // Skip opening quote.
+        self.advance();
+        let mut value = String::new();
+
+        while let Some(c) = self.peek() {
+            self.advance();
+
+            if c == quote {
+                // Handle escaped quote.
+                if self.peek() != Some(quote) {
+                    return Ok(value);
+                }
+
+                self.advance();
+            }
+
+            value.push(c);
+        }
+
+        let message = match quote {
+            '"' if !self.options.double_quoted_strings => {
+                "unterminated quoted identifier"
+            }
+            _ => "unterminated string literal",
+        };
+
+        Err(self.error(message))
     }
 
     /// Consume special symbol.
     ///
     /// # Returns
     ///  - `SQL token` - in case of success.
-    ///  - `None`      - otherwise.
-    fn consume_symbol(&mut self) -> Option<Token> {
-        if let Some(c) = self.input.peek() {
-            let token = match c {
-                ';' => Token::Semicolon,
-                _ => return None,
-            };
+    ///  - `Err`       - otherwise.
+    fn consume_symbol(&mut self) -> Result<Token> {
+        let Some(c) = self.peek() else {
+            return Ok(Token::End);
+        };
+
+        self.advance();
+
+        // Prefer the longest symbol sequence.
+        let token = match (c, self.peek()) {
+            ('<', Some('>')) | ('!', Some('=')) => {
+                self.consume_second(Token::NotEqual)
+            }
+            ('<', Some('=')) => self.consume_second(Token::LessEqual),
+            ('>', Some('=')) => self.consume_second(Token::GreaterEqual),
+            ('|', Some('|')) => self.consume_second(Token::Concat),
+            (':', Some(':')) => self.consume_second(Token::DoubleColon),
+            ('$', _) => return self.consume_placeholder(),
+            ('?', _) => Token::Placeholder(None),
+            ('(', _) => Token::LeftParen,
+            (')', _) => Token::RightParen,
+            (',', _) => Token::Comma,
+            ('.', _) => Token::Dot,
+            ('*', _) => Token::Asterisk,
+            ('+', _) => Token::Plus,
+            ('-', _) => Token::Minus,
+            ('/', _) => Token::Slash,
+            ('%', _) => Token::Percent,
+            ('=', _) => Token::Equal,
+            ('<', _) => Token::Less,
+            ('>', _) => Token::Greater,
+            (';', _) => Token::Semicolon,
+            _ => return Err(self.error(format!("unexpected character '{c}'"))),
+        };
+
+        log::debug!("Found symbol: {}", token);
+        Ok(token)
+    }
+
+    /// Consume second character of two-character symbol.
+    ///
+    /// # Parameters
+    /// - `token` - given symbol token.
+    ///
+    /// # Returns
+    /// - Given symbol token.
+    #[inline(always)]
+    fn consume_second(&mut self, token: Token) -> Token {
+        self.advance();
+        token
+    }
 
-            log::debug!("Found symbol: '{}'", c);
-            return Some(token);
+    /// Consume numbered placeholder (`$1`, `$2`, etc.).
+    ///
+    /// # Returns
+    ///  - `SQL token` - in case of success.
+    ///  - `Err`       - otherwise.
+    fn consume_placeholder(&mut self) -> Result<Token> {
+        let start = self.offset;
+        self.skip_while(|c| c.is_ascii_digit());
+
+        let index = match self.input[start..self.offset].parse::<u32>() {
+            Ok(index) if index > 0 => index,
+            _ => return Err(self.error("invalid placeholder index")),
+        };
+
+        self.check_number_end()?;
+        Ok(Token::Placeholder(Some(index)))
+    }
+}
+
+impl Iterator for Lexer<'_> {
+    type Item = Result<Spanned<Token>>;
+
+    /// Get next token.
+    ///
+    /// # Returns
+    /// - `Ok(SQL token)` - in case of success.
+    /// - `Err`           - in case of failure. Iteration stops after error.
+    /// - `None`          - in case of reaching end of SQL code.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
         }
 
-        None
+        match self.next_token() {
+            Ok(Spanned {
+                value: Token::End, ..
+            }) => {
+                self.finished = true;
+                None
+            }
+            Err(error) => {
+                self.finished = true;
+                Some(Err(error))
+            }
+            token => Some(token),
+        }
     }
 }
 
+impl FusedIterator for Lexer<'_> {}
+
+/// Check whether character can start identifier.
+///
+/// # Parameters
+/// - `c` - given character to check.
+///
+/// # Returns
+/// - `true`  - if character can start identifier.
+/// - `false` - otherwise.
+#[inline(always)]
+fn is_identifier_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_'
+}
+
+/// Check whether character can be part of identifier.
+///
+/// # Parameters
+/// - `c` - given character to check.
+///
+/// # Returns
+/// - `true`  - if character can be part of identifier.
+/// - `false` - otherwise.
+#[inline(always)]
+fn is_identifier_part(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$' || is_combining_mark(c)
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
-    use crate::compiler::lexer::Keyword::*;
+    use crate::compiler::lexer::Keyword::{Create, Database};
 
     #[test]
     fn test_next_token() {
         let input = "     CREATE     DATABASE    \"MyDB\"      ;     ";
         let mut lexer = Lexer::new(input);
 
-        let token = lexer.next_token();
-        assert_eq!(token, Some(Token::Keyword(Create)));
+        let token = lexer.next_token().map(|token| token.value);
+        assert_eq!(token, Ok(Token::Keyword(Create)));
 
-        let token = lexer.next_token();
-        assert_eq!(token, Some(Token::Keyword(Database)));
+        let token = lexer.next_token().map(|token| token.value);
+        assert_eq!(token, Ok(Token::Keyword(Database)));
 
-        let token = lexer.next_token();
-        assert_eq!(token, Some(Token::String("MyDB".to_string())));
+        let token = lexer.next_token().map(|token| token.value);
+        assert_eq!(token, Ok(Token::QuotedIdentifier("MyDB".to_string())));
 
-        let token = lexer.next_token();
-        assert_eq!(token, Some(Token::Semicolon));
+        let token = lexer.next_token().map(|token| token.value);
+        assert_eq!(token, Ok(Token::Semicolon));
 
-        let token = lexer.next_token();
-        assert_eq!(token, Some(Token::End));
+        let token = lexer.next_token().map(|token| token.value);
+        assert_eq!(token, Ok(Token::End));
 
         // Check that end was reached again.
-        let token = lexer.next_token();
-        assert_eq!(token, Some(Token::End));
+        let token = lexer.next_token().map(|token| token.value);
+        assert_eq!(token, Ok(Token::End));
     }
 
     #[test]
@@ -201,24 +811,24 @@ pub mod tests {
         let input = "     CreAtE     DATAbase    \"  MyDB  \"      ;     ";
         let mut lexer = Lexer::new(input);
 
-        let token = lexer.next_token();
-        assert_eq!(token, Some(Token::Keyword(Create)));
+        let token = lexer.next_token().map(|token| token.value);
+        assert_eq!(token, Ok(Token::Keyword(Create)));
 
-        let token = lexer.next_token();
-        assert_eq!(token, Some(Token::Keyword(Database)));
+        let token = lexer.next_token().map(|token| token.value);
+        assert_eq!(token, Ok(Token::Keyword(Database)));
 
-        let token = lexer.next_token();
-        assert_eq!(token, Some(Token::String("  MyDB  ".to_string())));
+        let token = lexer.next_token().map(|token| token.value);
+        assert_eq!(token, Ok(Token::QuotedIdentifier("  MyDB  ".to_string())));
 
-        let token = lexer.next_token();
-        assert_eq!(token, Some(Token::Semicolon));
+        let token = lexer.next_token().map(|token| token.value);
+        assert_eq!(token, Ok(Token::Semicolon));
 
-        let token = lexer.next_token();
-        assert_eq!(token, Some(Token::End));
+        let token = lexer.next_token().map(|token| token.value);
+        assert_eq!(token, Ok(Token::End));
 
         // Check that end was reached again.
-        let token = lexer.next_token();
-        assert_eq!(token, Some(Token::End));
+        let token = lexer.next_token().map(|token| token.value);
+        assert_eq!(token, Ok(Token::End));
     }
 
     #[test]
@@ -226,7 +836,345 @@ pub mod tests {
         let input = "     ";
         let mut lexer = Lexer::new(input);
 
-        let token = lexer.next_token();
-        assert_eq!(token, Some(Token::End));
+        let token = lexer.next_token().map(|token| token.value);
+        assert_eq!(token, Ok(Token::End));
+    }
+
+    #[test]
+    fn test_unexpected_character() {
+        let input = "CREATE\n  DATABASE @";
+        let mut lexer = Lexer::new(input);
+
+        assert_eq!(lexer.next_token().unwrap().value, Token::Keyword(Create));
+        assert_eq!(lexer.next_token().unwrap().value, Token::Keyword(Database));
+
+        let error = lexer.next_token().unwrap_err();
+        assert_eq!(error.kind, ErrorKind::Lexical);
+        assert_eq!(error.span, Span::new(18, 19, 2, 12));
+    }
+
+    #[test]
+    fn test_token_spans() {
+        let input = "CREATE DATABASE\n\t\"Мой\";";
+        let mut lexer = Lexer::new(input);
+
+        let spans = [
+            Span::new(0, 6, 1, 1),
+            Span::new(7, 15, 1, 8),
+            Span::new(17, 25, 2, 2),
+            Span::new(25, 26, 2, 7),
+            Span::new(26, 26, 2, 8),
+        ];
+
+        for span in spans {
+            assert_eq!(lexer.next_token().unwrap().span, span);
+        }
+    }
+
+    #[test]
+    fn test_numbers() {
+        let input =
+            "10 255 2.5 .5 1. 1.5e-3 2E10 0x1F 0XfF 9223372036854775808";
+        let mut lexer = Lexer::new(input);
+
+        let tokens = [
+            Token::Integer(10),
+            Token::Integer(255),
+            Token::Float(2.5),
+            Token::Float(0.5),
+            Token::Float(1.0),
+            Token::Float(1.5e-3),
+            Token::Float(2e10),
+            Token::Integer(0x1F),
+            Token::Integer(0xFF),
+            Token::Integer(MAX_INTEGER_MAGNITUDE),
+            Token::End,
+        ];
+
+        for token in tokens {
+            assert_eq!(lexer.next_token().unwrap().value, token);
+        }
+    }
+
+    #[test]
+    fn test_invalid_numbers() {
+        let inputs = [
+            "9223372036854775809",
+            "0x8000000000000001",
+            "1e400",
+            "1e",
+            "0x",
+            "123abc",
+        ];
+
+        for input in inputs {
+            let error = Lexer::new(input).next_token().unwrap_err();
+            assert_eq!(error.kind, ErrorKind::Lexical);
+            assert_eq!(error.span.end, input.len());
+        }
+    }
+
+    #[test]
+    fn test_symbols() {
+        let input = "( ) , . * + - / % = <> != < <= > >= || :: ? $12 ;<=>";
+        let mut lexer = Lexer::new(input);
+
+        let tokens = [
+            Token::LeftParen,
+            Token::RightParen,
+            Token::Comma,
+            Token::Dot,
+            Token::Asterisk,
+            Token::Plus,
+            Token::Minus,
+            Token::Slash,
+            Token::Percent,
+            Token::Equal,
+            Token::NotEqual,
+            Token::NotEqual,
+            Token::Less,
+            Token::LessEqual,
+            Token::Greater,
+            Token::GreaterEqual,
+            Token::Concat,
+            Token::DoubleColon,
+            Token::Placeholder(None),
+            Token::Placeholder(Some(12)),
+            Token::Semicolon,
+            Token::LessEqual,
+            Token::Greater,
+            Token::End,
+        ];
+
+        for token in tokens {
+            assert_eq!(lexer.next_token().unwrap().value, token);
+        }
+    }
+
+    #[test]
+    fn test_invalid_symbols() {
+        for input in ["|", ":", "!", "$", "$0", "$1a"] {
+            let error = Lexer::new(input).next_token().unwrap_err();
+            assert_eq!(error.kind, ErrorKind::Lexical);
+        }
+    }
+
+    #[test]
+    fn test_strings_and_identifiers() {
+        let input = "'it''s' \"My \"\"DB\"\"\" MyTable";
+        let mut lexer = Lexer::new(input);
+
+        let tokens = [
+            Token::String("it's".to_string()),
+            Token::QuotedIdentifier("My \"DB\"".to_string()),
+            Token::Identifier("mytable".to_string()),
+            Token::End,
+        ];
+
+        for token in tokens {
+            assert_eq!(lexer.next_token().unwrap().value, token);
+        }
+    }
+
+    #[test]
+    fn test_double_quoted_strings() {
+        let options = LexerOptions {
+            double_quoted_strings: true,
+            ..Default::default()
+        };
+        let mut lexer = Lexer::with_options("\"MyDB\" 'x'", options);
+
+        let token = lexer.next_token().unwrap().value;
+        assert_eq!(token, Token::String("MyDB".to_string()));
+
+        let token = lexer.next_token().unwrap().value;
+        assert_eq!(token, Token::String("x".to_string()));
+    }
+
+    #[test]
+    fn test_comments() {
+        let input = "-- header\nCREATE /* outer /* inner */ still outer */ \
+                     DATABASE -- trailing\n/**/;--";
+        let mut lexer = Lexer::new(input);
+
+        let tokens = [
+            Token::Keyword(Create),
+            Token::Keyword(Database),
+            Token::Semicolon,
+            Token::End,
+        ];
+
+        for token in tokens {
+            assert_eq!(lexer.next_token().unwrap().value, token);
+        }
+    }
+
+    #[test]
+    fn test_empty_literals() {
+        let options = LexerOptions {
+            double_quoted_strings: true,
+            ..Default::default()
+        };
+        let mut lexer = Lexer::with_options("'' \"\"", options);
+
+        let token = lexer.next_token().unwrap().value;
+        assert_eq!(token, Token::String(String::new()));
+
+        let token = lexer.next_token().unwrap().value;
+        assert_eq!(token, Token::String(String::new()));
+
+        let error = Lexer::new("\"\"").next_token().unwrap_err();
+        assert_eq!(error.kind, ErrorKind::Lexical);
+    }
+
+    #[test]
+    fn test_unterminated() {
+        let inputs = [
+            ("CREATE 'abc", "unterminated string literal"),
+            ("CREATE \"abc''", "unterminated quoted identifier"),
+            ("CREATE /* a /* b */", "unterminated block comment"),
+        ];
+
+        for (input, message) in inputs {
+            let mut lexer = Lexer::new(input);
+            lexer.next_token().unwrap();
+
+            let error = lexer.next_token().unwrap_err();
+            assert_eq!(error.message, message);
+            assert_eq!(error.span, Span::new(7, input.len(), 1, 8));
+        }
+    }
+
+    #[test]
+    fn test_identifiers() {
+        // Decomposed "é" (U+0065 U+0301) must match precomposed U+00E9.
+        let input = "user_id table2 _tmp price$ Cafe\u{301} \"Caf\u{e9}\"";
+        let mut lexer = Lexer::new(input);
+
+        let tokens = [
+            Token::Identifier("user_id".to_string()),
+            Token::Identifier("table2".to_string()),
+            Token::Identifier("_tmp".to_string()),
+            Token::Identifier("price$".to_string()),
+            Token::Identifier("caf\u{e9}".to_string()),
+            Token::QuotedIdentifier("Caf\u{e9}".to_string()),
+            Token::End,
+        ];
+
+        for token in tokens {
+            assert_eq!(lexer.next_token().unwrap().value, token);
+        }
+    }
+
+    #[test]
+    fn test_identifier_length() {
+        let name = "a".repeat(MAX_IDENTIFIER_LENGTH);
+        let token = Lexer::new(&name).next_token().unwrap().value;
+        assert_eq!(token, Token::Identifier(name.clone()));
+
+        for input in [format!("{name}a"), format!("\"{name}a\"")] {
+            let error = Lexer::new(&input).next_token().unwrap_err();
+            assert_eq!(error.kind, ErrorKind::Lexical);
+        }
+    }
+
+    #[test]
+    fn test_iterator() {
+        let tokens: Vec<_> = Lexer::new("CREATE DATABASE db;")
+            .map(|token| token.unwrap().value)
+            .collect();
+
+        let correct_tokens = [
+            Token::Keyword(Create),
+            Token::Keyword(Database),
+            Token::Identifier("db".to_string()),
+            Token::Semicolon,
+        ];
+
+        assert_eq!(tokens, correct_tokens);
+
+        // Check that iteration stops after error.
+        let mut lexer = Lexer::new("CREATE @ DATABASE");
+        assert!(lexer.next().unwrap().is_ok());
+        assert!(lexer.next().unwrap().is_err());
+        assert!(lexer.next().is_none());
+    }
+
+    #[test]
+    fn test_preserve_trivia() {
+        let input = "-- header\nCREATE  /* a /* b */ */DATABASE\t\"db\"; --";
+        let options = LexerOptions {
+            preserve_trivia: true,
+            ..Default::default()
+        };
+
+        let tokens: Vec<_> = Lexer::with_options(input, options)
+            .map(|token| token.unwrap())
+            .collect();
+
+        let correct_tokens = [
+            Token::Comment("-- header".to_string()),
+            Token::Whitespace("\n".to_string()),
+            Token::Keyword(Create),
+            Token::Whitespace("  ".to_string()),
+            Token::Comment("/* a /* b */ */".to_string()),
+            Token::Keyword(Database),
+            Token::Whitespace("\t".to_string()),
+            Token::QuotedIdentifier("db".to_string()),
+            Token::Semicolon,
+            Token::Whitespace(" ".to_string()),
+            Token::Comment("--".to_string()),
+        ];
+
+        let values: Vec<_> = tokens.iter().map(|t| t.value.clone()).collect();
+        assert_eq!(values, correct_tokens);
+
+        // Check that tokens cover SQL code without gaps.
+        let text: String = tokens
+            .iter()
+            .map(|t| &input[t.span.start..t.span.end])
+            .collect();
+        assert_eq!(text, input);
+    }
+
+    #[test]
+    fn test_special_literals() {
+        let input = "X'DEADbeef' x'' B'1010' E'a\\nb\\t\\'c''d\\\\' \
+                     e'\\x41\\101\\u00e9\\U0001F600' ex";
+        let mut lexer = Lexer::new(input);
+
+        let tokens = [
+            Token::Blob(vec![0xDE, 0xAD, 0xBE, 0xEF]),
+            Token::Blob(vec![]),
+            Token::BitString(vec![true, false, true, false]),
+            Token::String("a\nb\t'c'd\\".to_string()),
+            Token::String("AAé😀".to_string()),
+            Token::Identifier("ex".to_string()),
+            Token::End,
+        ];
+
+        for token in tokens {
+            assert_eq!(lexer.next_token().unwrap().value, token);
+        }
+    }
+
+    #[test]
+    fn test_invalid_special_literals() {
+        let inputs = [
+            "X'ABC'",
+            "X'GG'",
+            "B'102'",
+            "E'\\q'",
+            "E'\\x'",
+            "E'\\u12'",
+            "E'\\UFFFFFFFF'",
+            "E'\\0'",
+            "E'abc\\'",
+        ];
+
+        for input in inputs {
+            let error = Lexer::new(input).next_token().unwrap_err();
+            assert_eq!(error.kind, ErrorKind::Lexical, "{input}");
+        }
     }
 }
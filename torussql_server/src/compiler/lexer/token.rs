@@ -5,56 +5,82 @@
 
 //! SQL tokens related declarations.
 
-use std::{
-    convert::TryFrom,
-    fmt::{Display, Formatter},
-};
+pub use super::keyword::Keyword;
+use std::fmt::{Display, Formatter};
+use torussql_sdk::error::Span;
+
+/// Struct that attaches SQL code position to a value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spanned<T> {
+    /// Wrapped value.
+    pub value: T,
+    /// Position of the value in SQL code.
+    pub span: Span,
+}
+
+impl<T> Spanned<T> {
+    /// Construct new `Spanned` object.
+    ///
+    /// # Parameters
+    /// - `value` - given value to wrap.
+    /// - `span`  - given value position.
+    ///
+    /// # Returns
+    /// - New `Spanned` object.
+    pub const fn new(value: T, span: Span) -> Self {
+        Self { value, span }
+    }
+}
 
 /// SQL token types enumeration.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Token {
     Keyword(Keyword),
+    Identifier(String),
+    QuotedIdentifier(String),
     String(String),
+    Blob(Vec<u8>),
+    BitString(Vec<bool>),
+    Integer(u64),
+    Float(f64),
+    Placeholder(Option<u32>),
+    LeftParen,
+    RightParen,
+    Comma,
+    Dot,
+    Asterisk,
+    Plus,
+    Minus,
+    Slash,
+    Percent,
+    Equal,
+    NotEqual,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+    Concat,
+    DoubleColon,
     Semicolon,
+    Whitespace(String),
+    Comment(String),
     End,
 }
 
-/// SQL keywords enumeration.
-#[derive(Debug, PartialEq)]
-pub enum Keyword {
-    Create,
-    Database,
-}
-
-impl TryFrom<&str> for Keyword {
-    // TODO: replace with TorusSQL error enum.
-    type Error = &'static str;
-
-    /// Try to convert string to SQL keyword.
-    ///
-    /// # Parameters
-    /// - `value` - given string value to convert.
+impl Token {
+    /// Check whether token is whitespace or comment.
     ///
     /// # Returns
-    /// - `SQL keyword` - in case of success.
-    /// - `Err`         - otherwise.
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        // Make string lowercase.
-        let lowercase_value = value.to_lowercase();
-        let value = lowercase_value.as_str();
-
-        let result = match value {
-            "create" => Self::Create,
-            "database" => Self::Database,
-            _ => return Err("Not a keyword"),
-        };
-
-        Ok(result)
+    /// - `true`  - if token does not affect SQL code meaning.
+    /// - `false` - otherwise.
+    #[inline(always)]
+    pub fn is_trivia(&self) -> bool {
+        matches!(self, Token::Whitespace(_) | Token::Comment(_))
     }
 }
 
-impl Display for Keyword {
-    /// Display SQL keyword.
+impl Display for Token {
+    /// Display SQL token.
     ///
     /// # Parameters
     /// - `f` - given formatter.
@@ -63,11 +89,42 @@ impl Display for Keyword {
     /// - `OK`  - in case of success.
     /// - `Err` - otherwise.
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let result = match self {
-            Keyword::Create => "CREATE",
-            Keyword::Database => "DATABASE",
-        };
-
-        f.write_str(result)
+        match self {
+            Token::Keyword(keyword) => write!(f, "keyword {keyword}"),
+            Token::Identifier(value) => write!(f, "identifier {value}"),
+            Token::QuotedIdentifier(value) => {
+                write!(f, "identifier \"{}\"", value.replace('"', "\"\""))
+            }
+            Token::String(value) => {
+                write!(f, "string '{}'", value.replace('\'', "''"))
+            }
+            Token::Blob(_) => f.write_str("blob literal"),
+            Token::BitString(_) => f.write_str("bit string literal"),
+            Token::Integer(value) => write!(f, "integer {value}"),
+            Token::Float(value) => write!(f, "number {value}"),
+            Token::Placeholder(None) => f.write_str("'?'"),
+            Token::Placeholder(Some(index)) => write!(f, "'${index}'"),
+            Token::LeftParen => f.write_str("'('"),
+            Token::RightParen => f.write_str("')'"),
+            Token::Comma => f.write_str("','"),
+            Token::Dot => f.write_str("'.'"),
+            Token::Asterisk => f.write_str("'*'"),
+            Token::Plus => f.write_str("'+'"),
+            Token::Minus => f.write_str("'-'"),
+            Token::Slash => f.write_str("'/'"),
+            Token::Percent => f.write_str("'%'"),
+            Token::Equal => f.write_str("'='"),
+            Token::NotEqual => f.write_str("'<>'"),
+            Token::Less => f.write_str("'<'"),
+            Token::LessEqual => f.write_str("'<='"),
+            Token::Greater => f.write_str("'>'"),
+            Token::GreaterEqual => f.write_str("'>='"),
+            Token::Concat => f.write_str("'||'"),
+            Token::DoubleColon => f.write_str("'::'"),
+            Token::Semicolon => f.write_str("';'"),
+            Token::Whitespace(_) => f.write_str("whitespace"),
+            Token::Comment(_) => f.write_str("comment"),
+            Token::End => f.write_str("end of input"),
+        }
     }
 }
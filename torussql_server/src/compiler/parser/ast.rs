@@ -6,6 +6,7 @@
 //! SQL Abstract Syntax Tree (AST) related declarations.
 
 /// SQL language types enumeration.
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Debug, PartialEq)]
 pub enum LanguageType {
     /// Data Definition Language - defines and manages database objects.
@@ -29,7 +30,87 @@ pub enum Statement {
     CreateDatabase {
         /// Database name.
         name: String,
+        /// Do nothing if database already exists.
+        if_not_exists: bool,
     },
+    /// Delete database.
+    DropDatabase {
+        /// Database name.
+        name: String,
+        /// Do nothing if database does not exist.
+        if_exists: bool,
+    },
+    /// Change database.
+    AlterDatabase {
+        /// Database name.
+        name: String,
+        /// Change to apply.
+        action: AlterDatabaseAction,
+    },
+    /// Select database for subsequent statements.
+    UseDatabase {
+        /// Database name.
+        name: String,
+    },
+    /// Create a new table.
+    CreateTable {
+        /// Table name.
+        name: String,
+        /// Do nothing if table already exists.
+        if_not_exists: bool,
+        /// Table columns definitions.
+        columns: Vec<ColumnDef>,
+        /// Table-level constraints.
+        constraints: Vec<Constraint>,
+    },
+    /// Change table.
+    AlterTable {
+        /// Table name.
+        name: String,
+        /// Change to apply.
+        action: AlterTableAction,
+    },
+    /// Delete tables.
+    DropTable {
+        /// Tables names.
+        names: Vec<String>,
+        /// Do nothing if table does not exist.
+        if_exists: bool,
+        /// Handling of objects that depend on tables.
+        behavior: DropBehavior,
+    },
+    /// Delete all rows from tables.
+    Truncate {
+        /// Tables names.
+        names: Vec<String>,
+    },
+    /// Add rows to table.
+    Insert {
+        /// Table name.
+        table: String,
+        /// Columns to fill (all table columns if empty).
+        columns: Vec<String>,
+        /// Rows to add.
+        source: InsertSource,
+    },
+    /// Change rows of table.
+    Update {
+        /// Table name.
+        table: String,
+        /// New columns values.
+        assignments: Vec<Assignment>,
+        /// Condition for rows to change (all rows if not specified).
+        selection: Option<Expr>,
+    },
+    /// Delete rows from table.
+    Delete {
+        /// Table name.
+        table: String,
+        /// Condition for rows to delete (all rows if not specified).
+        selection: Option<Expr>,
+    },
+    /// Read rows.
+    Query(Box<Query>),
 }
 
 impl Statement {
@@ -39,7 +120,825 @@ impl Statement {
     /// - SQL language type.
     pub fn language_type(&self) -> LanguageType {
         match self {
-            Statement::CreateDatabase { .. } => LanguageType::DDL,
+            Statement::CreateDatabase { .. }
+            | Statement::DropDatabase { .. }
+            | Statement::AlterDatabase { .. }
+            | Statement::CreateTable { .. }
+            | Statement::AlterTable { .. }
+            | Statement::DropTable { .. }
+            | Statement::Truncate { .. } => LanguageType::DDL,
+            Statement::Insert { .. }
+            | Statement::Update { .. }
+            | Statement::Delete { .. } => LanguageType::DML,
+            Statement::Query(_) => LanguageType::DQL,
+            Statement::UseDatabase { .. } => LanguageType::Vendor,
         }
     }
 }
+
+/// ALTER DATABASE statement actions enumeration.
+#[derive(Debug, PartialEq)]
+pub enum AlterDatabaseAction {
+    /// Rename database.
+    RenameTo(String),
+}
+
+/// ALTER TABLE statement actions enumeration.
+#[derive(Debug, PartialEq)]
+pub enum AlterTableAction {
+    /// Add column.
+    AddColumn(ColumnDef),
+    /// Delete column.
+    DropColumn {
+        /// Column name.
+        name: String,
+        /// Handling of objects that depend on column.
+        behavior: DropBehavior,
+    },
+    /// Rename column.
+    RenameColumn {
+        /// Column name.
+        name: String,
+        /// New column name.
+        new_name: String,
+    },
+    /// Change column data type.
+    AlterColumnType {
+        /// Column name.
+        name: String,
+        /// New column data type.
+        data_type: DataType,
+    },
+    /// Change column default value.
+    SetColumnDefault {
+        /// Column name.
+        name: String,
+        /// New column default value.
+        default: Expr,
+    },
+    /// Allow NULL values in column.
+    DropColumnNotNull(String),
+    /// Add table constraint.
+    AddConstraint(Constraint),
+    /// Delete table constraint.
+    DropConstraint {
+        /// Constraint name.
+        name: String,
+        /// Handling of objects that depend on constraint.
+        behavior: DropBehavior,
+    },
+    /// Rename table.
+    RenameTo(String),
+}
+
+/// Handling of dependent objects on deletion enumeration.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum DropBehavior {
+    /// Refuse to delete if there are dependent objects.
+    #[default]
+    Restrict,
+    /// Delete dependent objects as well.
+    Cascade,
+}
+
+/// Table column definition struct.
+#[derive(Debug, PartialEq)]
+pub struct ColumnDef {
+    /// Column name.
+    pub name: String,
+    /// Column data type.
+    pub data_type: DataType,
+    /// Forbid NULL values in column.
+    pub not_null: bool,
+    /// Value to use when column value is not specified.
+    pub default: Option<Expr>,
+    /// Column-level constraints (their column lists contain this column).
+    pub constraints: Vec<Constraint>,
+}
+
+/// Table constraint struct.
+#[derive(Debug, PartialEq)]
+pub struct Constraint {
+    /// Constraint name (generated by catalog if not specified).
+    pub name: Option<String>,
+    /// Constraint rule.
+    pub kind: ConstraintKind,
+}
+
+/// Table constraint rules enumeration.
+#[derive(Debug, PartialEq)]
+pub enum ConstraintKind {
+    /// Columns values are unique & not NULL, one per table.
+    PrimaryKey(Vec<String>),
+    /// Columns values are unique.
+    Unique(Vec<String>),
+    /// Expression must not evaluate to FALSE for any row.
+    Check(Expr),
+    /// Columns values must exist in the referenced table.
+    ForeignKey {
+        /// Referencing columns.
+        columns: Vec<String>,
+        /// Referenced table name.
+        table: String,
+        /// Referenced columns (primary key of referenced table if empty).
+        referred_columns: Vec<String>,
+        /// Action to perform when referenced row is deleted.
+        on_delete: ReferentialAction,
+        /// Action to perform when referenced row is updated.
+        on_update: ReferentialAction,
+    },
+}
+
+/// Foreign key referential actions enumeration.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ReferentialAction {
+    /// Fail at the end of statement if reference is broken.
+    #[default]
+    NoAction,
+    /// Fail immediately if reference is broken.
+    Restrict,
+    /// Delete or update referencing rows as well.
+    Cascade,
+    /// Set referencing columns to NULL.
+    SetNull,
+    /// Set referencing columns to their default values.
+    SetDefault,
+}
+
+/// INSERT statement rows sources enumeration.
+#[derive(Debug, PartialEq)]
+pub enum InsertSource {
+    /// Rows listed in `VALUES (...), (...)` clause.
+    Values(Vec<Vec<Expr>>),
+    /// Rows returned by query.
+    Query(Box<Query>),
+}
+
+/// UPDATE statement column assignment struct.
+#[derive(Debug, PartialEq)]
+pub struct Assignment {
+    /// Column name.
+    pub column: String,
+    /// New column value.
+    pub value: Expr,
+}
+
+/// SELECT query struct.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Query {
+    /// Common table expressions (`WITH` clause).
+    pub with: Option<With>,
+    /// Query body.
+    pub body: SetExpr,
+    /// Order of returned rows.
+    pub order_by: Vec<OrderByExpr>,
+    /// Maximum number of rows to return.
+    pub limit: Option<u64>,
+    /// Number of rows to skip.
+    pub offset: Option<u64>,
+}
+
+/// WITH clause struct.
+#[derive(Debug, Clone, PartialEq)]
+pub struct With {
+    /// Allow common table expressions to refer to themselves.
+    pub recursive: bool,
+    /// Common table expressions.
+    pub ctes: Vec<Cte>,
+}
+
+/// Common table expression struct (`name [(columns)] AS (query)`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cte {
+    /// Name of temporary table.
+    pub name: String,
+    /// Names of temporary table columns (empty = names of query columns).
+    pub columns: Vec<String>,
+    /// Query filling temporary table.
+    pub query: Box<Query>,
+}
+
+/// Query body enumeration.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SetExpr {
+    /// SELECT clause.
+    Select(Box<Select>),
+    /// Parenthesized query (`(SELECT ... ORDER BY ... LIMIT ...)`).
+    Query(Box<Query>),
+    /// Combination of rows of two queries.
+    SetOperation {
+        /// Combination type.
+        op: SetOperator,
+        /// Keep duplicate rows.
+        all: bool,
+        /// Left query.
+        left: Box<SetExpr>,
+        /// Right query.
+        right: Box<SetExpr>,
+    },
+}
+
+/// Set operators enumeration.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SetOperator {
+    /// Rows of both queries.
+    Union,
+    /// Rows returned by both queries.
+    Intersect,
+    /// Rows of left query not returned by right query.
+    Except,
+}
+
+/// SELECT clause struct.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Select {
+    /// Remove duplicate rows.
+    pub distinct: bool,
+    /// Values to return.
+    pub projection: Vec<SelectItem>,
+    /// Sources of rows to combine (no rows are read if empty).
+    pub from: Vec<TableRef>,
+    /// Condition for rows to return (all rows if not specified).
+    pub selection: Option<Expr>,
+    /// Expressions to group rows by (ordinals refer to `projection`).
+    pub group_by: Vec<Expr>,
+    /// Condition for groups to return (all groups if not specified).
+    pub having: Option<Expr>,
+    /// Window definitions referred by window functions (`WINDOW` clause).
+    pub windows: Vec<NamedWindow>,
+}
+
+/// SELECT query returned values enumeration.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SelectItem {
+    /// Value of expression.
+    Expr {
+        /// Returned expression.
+        expr: Expr,
+        /// Name of returned column.
+        alias: Option<String>,
+    },
+    /// All columns (`*`).
+    Wildcard,
+    /// All columns of given table (`t.*`).
+    QualifiedWildcard(String),
+}
+
+/// Source of rows enumeration.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TableRef {
+    /// Table.
+    Table {
+        /// Table name.
+        name: String,
+        /// Name to refer to table within query.
+        alias: Option<String>,
+    },
+    /// Result of subquery (`(SELECT ...) AS alias`).
+    Derived {
+        /// Subquery.
+        query: Box<Query>,
+        /// Name to refer to subquery result within query.
+        alias: String,
+    },
+    /// Combination of rows of two sources.
+    Join {
+        /// Left source of rows.
+        left: Box<TableRef>,
+        /// Right source of rows.
+        right: Box<TableRef>,
+        /// Join type.
+        kind: JoinKind,
+        /// Rows matching condition.
+        constraint: JoinConstraint,
+    },
+}
+
+/// JOIN types enumeration.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum JoinKind {
+    /// Only matching rows.
+    Inner,
+    /// Matching rows & unmatched rows of left source.
+    Left,
+    /// Matching rows & unmatched rows of right source.
+    Right,
+    /// Matching rows & unmatched rows of both sources.
+    Full,
+    /// All combinations of rows.
+    Cross,
+}
+
+/// JOIN rows matching conditions enumeration.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JoinConstraint {
+    /// Rows match if expression is TRUE (`ON expr`).
+    On(Expr),
+    /// Rows match if given columns are equal (`USING (columns)`).
+    Using(Vec<String>),
+    /// Rows match if all columns with the same names are equal (`NATURAL`).
+    Natural,
+    /// All rows match (`CROSS JOIN`).
+    None,
+}
+
+/// ORDER BY clause item struct.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderByExpr {
+    /// Sorting key.
+    pub expr: Expr,
+    /// Sort in descending order.
+    pub descending: bool,
+    /// Put NULL values before others (defaults to `descending`).
+    pub nulls_first: bool,
+}
+
+/// WINDOW clause item struct (`name AS (spec)`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct NamedWindow {
+    /// Window name.
+    pub name: String,
+    /// Window definition.
+    pub spec: WindowSpec,
+}
+
+/// Window of window function call enumeration (`OVER` clause).
+#[derive(Debug, Clone, PartialEq)]
+pub enum WindowType {
+    /// Reference to window defined in WINDOW clause (`OVER name`).
+    Named(String),
+    /// Inline window definition (`OVER (spec)`).
+    Spec(WindowSpec),
+}
+
+/// Window definition struct.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowSpec {
+    /// Window whose partitioning, ordering & frame are extended.
+    pub base: Option<String>,
+    /// Expressions to split rows into partitions by.
+    pub partition_by: Vec<Expr>,
+    /// Order of rows in partition.
+    pub order_by: Vec<OrderByExpr>,
+    /// Rows of partition visible to function (default frame if not specified).
+    pub frame: Option<WindowFrame>,
+}
+
+/// Window frame struct (`ROWS|RANGE|GROUPS BETWEEN start AND end`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowFrame {
+    /// Units of frame bounds offsets.
+    pub units: FrameUnits,
+    /// First row of frame.
+    pub start: FrameBound,
+    /// Last row of frame (`CURRENT ROW` if not specified).
+    pub end: FrameBound,
+}
+
+/// Window frame units enumeration.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FrameUnits {
+    /// Offsets are numbers of rows.
+    Rows,
+    /// Offsets are differences of ORDER BY key values.
+    Range,
+    /// Offsets are numbers of groups of peer rows.
+    Groups,
+}
+
+/// Window frame bound enumeration (in order of rows).
+#[derive(Debug, Clone, PartialEq)]
+pub enum FrameBound {
+    /// First row of partition.
+    UnboundedPreceding,
+    /// `offset PRECEDING`.
+    Preceding(Box<Expr>),
+    /// `CURRENT ROW`.
+    CurrentRow,
+    /// `offset FOLLOWING`.
+    Following(Box<Expr>),
+    /// Last row of partition.
+    UnboundedFollowing,
+}
+
+/// SQL data types enumeration.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DataType {
+    /// 16-bit signed integer.
+    SmallInt,
+    /// 32-bit signed integer.
+    Integer,
+    /// 64-bit signed integer.
+    BigInt,
+    /// Single precision floating-point number.
+    Real,
+    /// Double precision floating-point number.
+    Double,
+    /// Exact number with given total number of digits & digits after point.
+    Decimal {
+        /// Total number of digits (implementation-defined if not specified).
+        precision: Option<u8>,
+        /// Number of digits after decimal point.
+        scale: u8,
+    },
+    /// Variable-length string with given maximum length.
+    Varchar(u32),
+    /// Variable-length string of unlimited length.
+    Text,
+    /// Logical value.
+    Boolean,
+    /// Calendar date.
+    Date,
+    /// Time of day.
+    Time,
+    /// Date & time of day.
+    Timestamp,
+    /// Span of time.
+    Interval,
+    /// Binary data.
+    Blob,
+}
+
+/// SQL expression enumeration.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    /// Constant value.
+    Literal(Literal),
+    /// Statement parameter (`?` or `$1`), `None` stands for positional one.
+    Placeholder(Option<u32>),
+    /// Reference to column, optionally qualified with table name.
+    Column {
+        /// Table name or alias.
+        table: Option<String>,
+        /// Column name.
+        name: String,
+    },
+    /// Operation with single operand (`-x`, `NOT x`).
+    UnaryOp {
+        /// Operator.
+        op: UnaryOperator,
+        /// Operand.
+        expr: Box<Expr>,
+    },
+    /// Operation with two operands (`x + y`, `x AND y`).
+    BinaryOp {
+        /// Left operand.
+        left: Box<Expr>,
+        /// Operator.
+        op: BinaryOperator,
+        /// Right operand.
+        right: Box<Expr>,
+    },
+    /// `x IS [NOT] NULL`.
+    IsNull {
+        /// Checked expression.
+        expr: Box<Expr>,
+        /// Check that expression is not NULL.
+        negated: bool,
+    },
+    /// `x [NOT] BETWEEN low AND high`.
+    Between {
+        /// Checked expression.
+        expr: Box<Expr>,
+        /// Check that expression is out of range.
+        negated: bool,
+        /// Lower bound (inclusive).
+        low: Box<Expr>,
+        /// Upper bound (inclusive).
+        high: Box<Expr>,
+    },
+    /// `x [NOT] IN (y, z, ...)`.
+    InList {
+        /// Checked expression.
+        expr: Box<Expr>,
+        /// Check that expression is not in the list.
+        negated: bool,
+        /// List of values.
+        list: Vec<Expr>,
+    },
+    /// `x [NOT] LIKE|ILIKE pattern [ESCAPE escape]`.
+    Like {
+        /// Checked expression.
+        expr: Box<Expr>,
+        /// Check that expression does not match pattern.
+        negated: bool,
+        /// Ignore letters case (ILIKE).
+        case_insensitive: bool,
+        /// Pattern with `%` & `_` wildcards.
+        pattern: Box<Expr>,
+        /// Character that disables special meaning of wildcards.
+        escape: Option<Box<Expr>>,
+    },
+    /// Function call.
+    Function {
+        /// Function name.
+        name: String,
+        /// Function arguments.
+        args: FunctionArgs,
+        /// Aggregate only distinct values of arguments.
+        distinct: bool,
+        /// Condition for rows to aggregate (`FILTER (WHERE condition)`).
+        filter: Option<Box<Expr>>,
+        /// Window of rows to compute function over (`OVER` clause).
+        over: Option<WindowType>,
+    },
+    /// Query returning single value (`(SELECT ...)`).
+    ///
+    /// Subqueries may refer to columns of enclosing queries (correlated
+    /// subqueries), such references are resolved by planner.
+    Subquery(Box<Query>),
+    /// `x [NOT] IN (SELECT ...)`.
+    InSubquery {
+        /// Checked expression.
+        expr: Box<Expr>,
+        /// Check that expression is not returned by subquery.
+        negated: bool,
+        /// Query returning single column.
+        subquery: Box<Query>,
+    },
+    /// `EXISTS (SELECT ...)`.
+    Exists(Box<Query>),
+    /// `x op ANY|SOME|ALL (SELECT ...)`.
+    Quantified {
+        /// Left operand.
+        left: Box<Expr>,
+        /// Comparison operator.
+        op: BinaryOperator,
+        /// Whether comparison must hold for any or for all rows.
+        quantifier: Quantifier,
+        /// Query returning single column.
+        subquery: Box<Query>,
+    },
+    /// `CASE [operand] WHEN x THEN y ... [ELSE z] END`.
+    Case {
+        /// Value compared with WHEN values (conditions are checked if none).
+        operand: Option<Box<Expr>>,
+        /// WHEN conditions or values & THEN results pairs.
+        branches: Vec<(Expr, Expr)>,
+        /// Result if no branch matched (NULL if not specified).
+        else_result: Option<Box<Expr>>,
+    },
+    /// `CAST(x AS type)` or `x::type`.
+    Cast {
+        /// Converted expression.
+        expr: Box<Expr>,
+        /// Target data type.
+        data_type: DataType,
+    },
+    /// Constant of given type written as string (`DATE '2026-01-01'`).
+    TypedLiteral {
+        /// Constant data type.
+        data_type: DataType,
+        /// Constant representation.
+        value: String,
+    },
+    /// `COALESCE(x, y, ...)`.
+    Coalesce(Vec<Expr>),
+    /// `NULLIF(x, y)`.
+    NullIf {
+        /// Returned value.
+        left: Box<Expr>,
+        /// Value replaced with NULL.
+        right: Box<Expr>,
+    },
+}
+
+impl Expr {
+    /// Get direct subexpressions of this SQL expression.
+    ///
+    /// # Returns
+    /// - Subexpressions in order of appearance (excluding subqueries).
+    pub fn children(&self) -> Vec<&Expr> {
+        match self {
+            // Subqueries are separate scopes.
+            Expr::Literal(_)
+            | Expr::Placeholder(_)
+            | Expr::Column { .. }
+            | Expr::TypedLiteral { .. }
+            | Expr::Subquery(_)
+            | Expr::Exists(_) => Vec::new(),
+            Expr::UnaryOp { expr, .. }
+            | Expr::IsNull { expr, .. }
+            | Expr::Cast { expr, .. }
+            | Expr::InSubquery { expr, .. }
+            | Expr::Quantified { left: expr, .. } => vec![expr],
+            Expr::BinaryOp { left, right, .. }
+            | Expr::NullIf { left, right } => vec![left, right],
+            Expr::Coalesce(args) => args.iter().collect(),
+            Expr::Case {
+                operand,
+                branches,
+                else_result,
+            } => operand
+                .as_deref()
+                .into_iter()
+                .chain(branches.iter().flat_map(|(when, then)| [when, then]))
+                .chain(else_result.as_deref())
+                .collect(),
+            Expr::Between {
+                expr, low, high, ..
+            } => vec![expr, low, high],
+            Expr::InList { expr, list, .. } => {
+                std::iter::once(&**expr).chain(list).collect()
+            }
+            Expr::Like {
+                expr,
+                pattern,
+                escape,
+                ..
+            } => [expr, pattern]
+                .into_iter()
+                .chain(escape)
+                .map(|e| &**e)
+                .collect(),
+            Expr::Function {
+                args, filter, over, ..
+            } => {
+                let args = match args {
+                    FunctionArgs::List(args) => args.as_slice(),
+                    FunctionArgs::Wildcard => &[],
+                };
+
+                let (partition_by, order_by) = match over {
+                    Some(WindowType::Spec(spec)) => {
+                        (spec.partition_by.as_slice(), spec.order_by.as_slice())
+                    }
+                    _ => (&[] as &[Expr], &[] as &[OrderByExpr]),
+                };
+
+                args.iter()
+                    .chain(filter.as_deref())
+                    .chain(partition_by)
+                    .chain(order_by.iter().map(|item| &item.expr))
+                    .collect()
+            }
+        }
+    }
+
+    /// Check whether this SQL expression is aggregate function call.
+    ///
+    /// # Returns
+    /// - `true`  - if expression is COUNT, SUM, AVG, MIN or MAX call.
+    /// - `false` - otherwise (including calls with OVER clause).
+    pub fn is_aggregate(&self) -> bool {
+        match self {
+            Expr::Function {
+                name, over: None, ..
+            } => is_aggregate_function(name),
+            _ => false,
+        }
+    }
+
+    /// Check whether this SQL expression contains window function call.
+    ///
+    /// # Returns
+    /// - `true`  - if expression contains function call with OVER clause.
+    /// - `false` - otherwise.
+    pub fn contains_window(&self) -> bool {
+        matches!(self, Expr::Function { over: Some(_), .. })
+            || self.children().into_iter().any(Expr::contains_window)
+    }
+
+    /// Check whether this SQL expression contains subquery.
+    ///
+    /// # Returns
+    /// - `true`  - if expression contains subquery.
+    /// - `false` - otherwise.
+    pub fn contains_subquery(&self) -> bool {
+        matches!(
+            self,
+            Expr::Subquery(_)
+                | Expr::InSubquery { .. }
+                | Expr::Exists(_)
+                | Expr::Quantified { .. }
+        ) || self.children().into_iter().any(Expr::contains_subquery)
+    }
+
+    /// Check whether this SQL expression contains aggregate function call.
+    ///
+    /// # Returns
+    /// - `true`  - if expression contains aggregate function call.
+    /// - `false` - otherwise.
+    pub fn contains_aggregate(&self) -> bool {
+        self.is_aggregate()
+            || self.children().into_iter().any(Expr::contains_aggregate)
+    }
+}
+
+/// Check whether function with given name is aggregate function.
+///
+/// # Parameters
+/// - `name` - given function name.
+///
+/// # Returns
+/// - `true`  - if function is COUNT, SUM, AVG, MIN or MAX.
+/// - `false` - otherwise.
+pub fn is_aggregate_function(name: &str) -> bool {
+    matches!(name, "count" | "sum" | "avg" | "min" | "max")
+}
+
+/// Check whether function with given name is window function.
+///
+/// # Parameters
+/// - `name` - given function name.
+///
+/// # Returns
+/// - `true`  - if function can only be called with OVER clause.
+/// - `false` - otherwise.
+pub fn is_window_function(name: &str) -> bool {
+    matches!(
+        name,
+        "row_number"
+            | "rank"
+            | "dense_rank"
+            | "percent_rank"
+            | "cume_dist"
+            | "ntile"
+            | "lag"
+            | "lead"
+            | "first_value"
+            | "last_value"
+            | "nth_value"
+    )
+}
+
+/// Function call arguments enumeration.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FunctionArgs {
+    /// List of expressions.
+    List(Vec<Expr>),
+    /// All columns (`COUNT(*)`).
+    Wildcard,
+}
+
+/// Subquery comparison quantifiers enumeration.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Quantifier {
+    /// `ANY` or `SOME`.
+    Any,
+    /// `ALL`.
+    All,
+}
+
+/// SQL unary operators enumeration.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UnaryOperator {
+    /// `+x`.
+    Plus,
+    /// `-x`.
+    Minus,
+    /// `NOT x`.
+    Not,
+}
+
+/// SQL binary operators enumeration.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BinaryOperator {
+    /// `x + y`.
+    Add,
+    /// `x - y`.
+    Subtract,
+    /// `x * y`.
+    Multiply,
+    /// `x / y`.
+    Divide,
+    /// `x % y`.
+    Modulo,
+    /// `x || y`.
+    Concat,
+    /// `x = y`.
+    Equal,
+    /// `x <> y`.
+    NotEqual,
+    /// `x < y`.
+    Less,
+    /// `x <= y`.
+    LessEqual,
+    /// `x > y`.
+    Greater,
+    /// `x >= y`.
+    GreaterEqual,
+    /// `x AND y`.
+    And,
+    /// `x OR y`.
+    Or,
+}
+
+/// SQL literal values enumeration.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Literal {
+    /// Unknown value.
+    Null,
+    /// Logical value.
+    Boolean(bool),
+    /// Integer number.
+    Integer(i64),
+    /// Floating-point number.
+    Float(f64),
+    /// Text.
+    String(String),
+    /// Binary data.
+    Blob(Vec<u8>),
+    /// Sequence of bits.
+    BitString(Vec<bool>),
+}
@@ -0,0 +1,1499 @@
+// Project name: TorusSQL.
+// Description: Relational database management system.
+// Licence: GPL-3.0.
+// Author: Alexander (@alkuzin).
+
+//! SQL DDL related statements parsing declarations.
+
+use crate::compiler::{
+    lexer::token::{Keyword, Spanned, Token},
+    parser::{
+        Parser,
+        ast::{
+            AlterDatabaseAction, AlterTableAction, ColumnDef, Constraint,
+            ConstraintKind, DataType, DropBehavior, Expr, ReferentialAction,
+            Statement,
+        },
+    },
+};
+use torussql_sdk::error::{ErrorKind, Result, Span, TorusError};
+
+/// Maximum precision of DECIMAL data type.
+const MAX_DECIMAL_PRECISION: u64 = 38;
+
+impl Parser<'_> {
+    /// Parse create statement.
+    ///
+    /// # Returns
+    /// - `SQL statement` - in case of success.
+    /// - `Err`           - in case of failure.
+    pub(super) fn parse_create(&mut self) -> Result<Statement> {
+        self.expect_keyword(Keyword::Create)?;
+
+        match self.current_token()? {
+            // Handle CREATE DATABASE statement.
+            Token::Keyword(Keyword::Database) => self.parse_create_database(),
+            // Handle CREATE TABLE statement.
+            Token::Keyword(Keyword::Table) => self.parse_create_table(),
+            _ => Err(self.unexpected("DATABASE or TABLE")),
+        }
+    }
+
+    /// Parse create database statement.
+    ///
+    /// # Returns
+    /// - `SQL statement` - in case of success.
+    /// - `Err`           - in case of failure.
+    fn parse_create_database(&mut self) -> Result<Statement> {
+        self.expect_keyword(Keyword::Database)?;
+
+        let if_not_exists = self.parse_if_not_exists()?;
+        let name = self.parse_identifier("database name")?;
+
+        Ok(Statement::CreateDatabase {
+            name,
+            if_not_exists,
+        })
+    }
+
+    /// Parse create table statement.
+    ///
+    /// # Returns
+    /// - `SQL statement` - in case of success.
+    /// - `Err`           - in case of failure.
+    fn parse_create_table(&mut self) -> Result<Statement> {
+        self.expect_keyword(Keyword::Table)?;
+
+        let if_not_exists = self.parse_if_not_exists()?;
+        let name = self.parse_identifier("table name")?;
+
+        let mut columns: Vec<ColumnDef> = Vec::new();
+        let mut column_spans = Vec::new();
+        let mut constraints = Vec::new();
+        let mut primary_keys = 0;
+
+        let is_primary_key = |constraint: &Constraint| {
+            matches!(constraint.kind, ConstraintKind::PrimaryKey(_))
+        };
+
+        self.expect_token(&Token::LeftParen)?;
+
+        // Handle table elements: column definitions & table constraints.
+        loop {
+            let span = self.current_span()?;
+
+            if is_table_constraint(self.current_token()?) {
+                let constraint = self.parse_table_constraint()?;
+
+                primary_keys += is_primary_key(&constraint) as usize;
+                constraints.push(Spanned::new(constraint, span));
+            } else {
+                let column = self.parse_column_def()?;
+
+                if columns.iter().any(|other| other.name == column.name) {
+                    let message = format!(
+                        "column \"{}\" specified more than once",
+                        column.name
+                    );
+                    let kind = ErrorKind::Semantic;
+
+                    return Err(TorusError::new(kind, span, message));
+                }
+
+                let column_constraints = column.constraints.iter();
+
+                primary_keys += column_constraints
+                    .filter(|constraint| is_primary_key(constraint))
+                    .count();
+                columns.push(column);
+                column_spans.push(span);
+            }
+
+            if primary_keys > 1 {
+                let message = format!(
+                    "multiple primary keys for table \"{name}\" are not allowed"
+                );
+                let kind = ErrorKind::Semantic;
+
+                return Err(TorusError::new(kind, span, message));
+            }
+
+            if !self.consume_token(&Token::Comma)? {
+                break;
+            }
+        }
+
+        self.expect_token(&Token::RightParen)?;
+
+        // Constraints may precede columns they refer to.
+        for constraint in &constraints {
+            check_constraint_columns(
+                &columns,
+                &constraint.value,
+                constraint.span,
+            )?;
+        }
+
+        for (column, span) in columns.iter().zip(column_spans) {
+            for constraint in &column.constraints {
+                check_constraint_columns(&columns, constraint, span)?;
+            }
+        }
+
+        Ok(Statement::CreateTable {
+            name,
+            if_not_exists,
+            columns,
+            constraints: constraints.into_iter().map(|c| c.value).collect(),
+        })
+    }
+
+    /// Parse table column definition.
+    ///
+    /// # Returns
+    /// - `Column definition` - in case of success.
+    /// - `Err`               - in case of failure.
+    fn parse_column_def(&mut self) -> Result<ColumnDef> {
+        let name = self.parse_identifier("column name")?;
+        let data_type = self.parse_data_type()?;
+
+        let mut column = ColumnDef {
+            name,
+            data_type,
+            not_null: false,
+            default: None,
+            constraints: Vec::new(),
+        };
+
+        // Explicit NULL is kept only to detect conflicts with NOT NULL.
+        let mut nullable = false;
+
+        // Handle column options.
+        loop {
+            let span = self.current_span()?;
+
+            match self.current_token()? {
+                Token::Keyword(Keyword::Not | Keyword::Null) => {
+                    let not_null = self.consume_keyword(Keyword::Not)?;
+                    self.expect_keyword(Keyword::Null)?;
+
+                    let conflicting = match not_null {
+                        true => nullable,
+                        false => column.not_null,
+                    };
+
+                    if conflicting {
+                        let message = "conflicting NULL/NOT NULL declarations";
+                        let kind = ErrorKind::Semantic;
+
+                        return Err(TorusError::new(kind, span, message));
+                    }
+
+                    column.not_null |= not_null;
+                    nullable |= !not_null;
+                }
+                Token::Keyword(Keyword::Default) => {
+                    self.next_token();
+
+                    if column.default.is_some() {
+                        let name = &column.name;
+                        let message = format!(
+                            "multiple default values for column \"{name}\""
+                        );
+                        let kind = ErrorKind::Semantic;
+
+                        return Err(TorusError::new(kind, span, message));
+                    }
+
+                    column.default = Some(self.parse_constant_expr("DEFAULT")?);
+                }
+                Token::Keyword(
+                    Keyword::Constraint
+                    | Keyword::Primary
+                    | Keyword::Unique
+                    | Keyword::Check
+                    | Keyword::References,
+                ) => {
+                    let constraint =
+                        self.parse_column_constraint(&column.name)?;
+                    column.constraints.push(constraint);
+                }
+                _ => return Ok(column),
+            }
+        }
+    }
+
+    /// Parse column-level constraint.
+    ///
+    /// # Parameters
+    /// - `column` - given name of constrained column.
+    ///
+    /// # Returns
+    /// - `Table constraint` - in case of success.
+    /// - `Err`              - in case of failure.
+    fn parse_column_constraint(&mut self, column: &str) -> Result<Constraint> {
+        let name = self.parse_constraint_name()?;
+        let columns = vec![column.to_string()];
+
+        let kind = match self.current_token()? {
+            Token::Keyword(Keyword::Primary) => {
+                self.next_token();
+                self.expect_keyword(Keyword::Key)?;
+                ConstraintKind::PrimaryKey(columns)
+            }
+            Token::Keyword(Keyword::Unique) => {
+                self.next_token();
+                ConstraintKind::Unique(columns)
+            }
+            Token::Keyword(Keyword::Check) => {
+                ConstraintKind::Check(self.parse_check()?)
+            }
+            Token::Keyword(Keyword::References) => {
+                self.parse_references(columns)?
+            }
+            _ => {
+                let expected = "PRIMARY KEY, UNIQUE, CHECK or REFERENCES";
+                return Err(self.unexpected(expected));
+            }
+        };
+
+        Ok(Constraint { name, kind })
+    }
+
+    /// Parse table-level constraint.
+    ///
+    /// # Returns
+    /// - `Table constraint` - in case of success.
+    /// - `Err`              - in case of failure.
+    fn parse_table_constraint(&mut self) -> Result<Constraint> {
+        let name = self.parse_constraint_name()?;
+
+        let kind = match self.current_token()? {
+            Token::Keyword(Keyword::Primary) => {
+                self.next_token();
+                self.expect_keyword(Keyword::Key)?;
+                ConstraintKind::PrimaryKey(self.parse_column_list()?)
+            }
+            Token::Keyword(Keyword::Unique) => {
+                self.next_token();
+                ConstraintKind::Unique(self.parse_column_list()?)
+            }
+            Token::Keyword(Keyword::Check) => {
+                ConstraintKind::Check(self.parse_check()?)
+            }
+            Token::Keyword(Keyword::Foreign) => {
+                self.next_token();
+                self.expect_keyword(Keyword::Key)?;
+
+                let columns = self.parse_column_list()?;
+                self.parse_references(columns)?
+            }
+            _ => {
+                let expected = "PRIMARY KEY, UNIQUE, CHECK or FOREIGN KEY";
+                return Err(self.unexpected(expected));
+            }
+        };
+
+        Ok(Constraint { name, kind })
+    }
+
+    /// Parse optional `CONSTRAINT name` clause.
+    ///
+    /// # Returns
+    /// - `Constraint name` - if clause is present.
+    /// - `None`            - otherwise.
+    /// - `Err`             - in case of failure.
+    fn parse_constraint_name(&mut self) -> Result<Option<String>> {
+        if !self.consume_keyword(Keyword::Constraint)? {
+            return Ok(None);
+        }
+
+        Ok(Some(self.parse_identifier("constraint name")?))
+    }
+
+    /// Parse list of distinct column names enclosed in parentheses.
+    ///
+    /// # Returns
+    /// - Column names - in case of success.
+    /// - `Err`        - in case of failure.
+    pub(super) fn parse_column_list(&mut self) -> Result<Vec<String>> {
+        let mut columns: Vec<String> = Vec::new();
+
+        self.expect_token(&Token::LeftParen)?;
+
+        loop {
+            let span = self.current_span()?;
+            let column = self.parse_identifier("column name")?;
+
+            if columns.contains(&column) {
+                let message =
+                    format!("column \"{column}\" specified more than once");
+                let kind = ErrorKind::Semantic;
+
+                return Err(TorusError::new(kind, span, message));
+            }
+
+            columns.push(column);
+
+            if !self.consume_token(&Token::Comma)? {
+                break;
+            }
+        }
+
+        self.expect_token(&Token::RightParen)?;
+        Ok(columns)
+    }
+
+    /// Parse `CHECK (expr)` constraint.
+    ///
+    /// # Returns
+    /// - `Checked expression` - in case of success.
+    /// - `Err`                - in case of failure.
+    fn parse_check(&mut self) -> Result<Expr> {
+        self.expect_keyword(Keyword::Check)?;
+        self.expect_token(&Token::LeftParen)?;
+
+        let expr = self.parse_constant_expr("CHECK")?;
+        self.expect_token(&Token::RightParen)?;
+
+        Ok(expr)
+    }
+
+    /// Parse SQL expression stored in table definition.
+    ///
+    /// # Parameters
+    /// - `clause` - given name of clause containing expression.
+    ///
+    /// # Returns
+    /// - `SQL expression` - in case of success.
+    /// - `Err`            - in case of failure or forbidden subexpression.
+    fn parse_constant_expr(&mut self, clause: &str) -> Result<Expr> {
+        let span = self.current_span()?;
+        let expr = self.parse_row_expr(clause)?;
+
+        // Table definition must not depend on other tables content.
+        if expr.contains_subquery() {
+            let message = format!("subqueries are not allowed in {clause}");
+            let kind = ErrorKind::Semantic;
+
+            return Err(TorusError::new(kind, span, message));
+        }
+
+        Ok(expr)
+    }
+
+    /// Parse `REFERENCES table [(columns)] [ON DELETE|UPDATE action]` clause.
+    ///
+    /// # Parameters
+    /// - `columns` - given referencing columns.
+    ///
+    /// # Returns
+    /// - `Foreign key constraint` - in case of success.
+    /// - `Err`                    - in case of failure.
+    fn parse_references(
+        &mut self,
+        columns: Vec<String>,
+    ) -> Result<ConstraintKind> {
+        self.expect_keyword(Keyword::References)?;
+
+        let table = self.parse_identifier("table name")?;
+        let span = self.current_span()?;
+
+        let referred_columns = match self.current_token()? {
+            Token::LeftParen => self.parse_column_list()?,
+            _ => Vec::new(),
+        };
+
+        if !referred_columns.is_empty()
+            && referred_columns.len() != columns.len()
+        {
+            let message = "number of referencing and referenced columns \
+                           for foreign key disagree";
+            let kind = ErrorKind::Semantic;
+
+            return Err(TorusError::new(kind, span, message));
+        }
+
+        let mut on_delete = None;
+        let mut on_update = None;
+
+        // Handle referential actions in any order.
+        while let Token::Keyword(Keyword::On) = self.current_token()? {
+            let span = self.current_span()?;
+            self.next_token();
+
+            let (clause, event) = match self.current_token()? {
+                Token::Keyword(Keyword::Delete) => (&mut on_delete, "DELETE"),
+                Token::Keyword(Keyword::Update) => (&mut on_update, "UPDATE"),
+                _ => return Err(self.unexpected("DELETE or UPDATE")),
+            };
+
+            if clause.is_some() {
+                let message = format!("duplicate ON {event} clause");
+                let kind = ErrorKind::Semantic;
+
+                return Err(TorusError::new(kind, span, message));
+            }
+
+            self.next_token();
+            *clause = Some(self.parse_referential_action()?);
+        }
+
+        Ok(ConstraintKind::ForeignKey {
+            columns,
+            table,
+            referred_columns,
+            on_delete: on_delete.unwrap_or_default(),
+            on_update: on_update.unwrap_or_default(),
+        })
+    }
+
+    /// Parse foreign key referential action.
+    ///
+    /// # Returns
+    /// - `Referential action` - in case of success.
+    /// - `Err`                - in case of failure.
+    fn parse_referential_action(&mut self) -> Result<ReferentialAction> {
+        let action = match self.current_token()? {
+            Token::Keyword(Keyword::Cascade) => ReferentialAction::Cascade,
+            Token::Keyword(Keyword::Restrict) => ReferentialAction::Restrict,
+            Token::Keyword(Keyword::Set) => {
+                self.next_token();
+
+                match self.current_token()? {
+                    Token::Keyword(Keyword::Null) => ReferentialAction::SetNull,
+                    Token::Keyword(Keyword::Default) => {
+                        ReferentialAction::SetDefault
+                    }
+                    _ => return Err(self.unexpected("NULL or DEFAULT")),
+                }
+            }
+            Token::Keyword(Keyword::No) => {
+                self.next_token();
+
+                match self.current_token()? {
+                    Token::Keyword(Keyword::Action) => {
+                        ReferentialAction::NoAction
+                    }
+                    _ => return Err(self.unexpected("ACTION")),
+                }
+            }
+            _ => {
+                let expected = "CASCADE, RESTRICT, SET NULL, SET DEFAULT \
+                                or NO ACTION";
+                return Err(self.unexpected(expected));
+            }
+        };
+
+        self.next_token();
+        Ok(action)
+    }
+
+    /// Parse SQL data type.
+    ///
+    /// # Returns
+    /// - `SQL data type` - in case of success.
+    /// - `Err`           - in case of failure.
+    pub(super) fn parse_data_type(&mut self) -> Result<DataType> {
+        let Token::Keyword(keyword) = self.current_token()? else {
+            return Err(self.unexpected("data type"));
+        };
+
+        let data_type = match keyword {
+            Keyword::Smallint => DataType::SmallInt,
+            Keyword::Integer | Keyword::Int => DataType::Integer,
+            Keyword::Bigint => DataType::BigInt,
+            Keyword::Real => DataType::Real,
+            Keyword::Double => {
+                self.next_token();
+                self.consume_keyword(Keyword::Precision)?;
+                return Ok(DataType::Double);
+            }
+            Keyword::Decimal | Keyword::Numeric | Keyword::Dec => {
+                self.next_token();
+                return self.parse_decimal_parameters();
+            }
+            Keyword::Varchar => {
+                self.next_token();
+                return self.parse_varchar_length();
+            }
+            Keyword::Character | Keyword::Char => {
+                self.next_token();
+                self.expect_keyword(Keyword::Varying)?;
+                return self.parse_varchar_length();
+            }
+            Keyword::Text => DataType::Text,
+            Keyword::Boolean => DataType::Boolean,
+            Keyword::Date => DataType::Date,
+            Keyword::Time => DataType::Time,
+            Keyword::Timestamp => DataType::Timestamp,
+            Keyword::Interval => DataType::Interval,
+            Keyword::Blob => DataType::Blob,
+            _ => return Err(self.unexpected("data type")),
+        };
+
+        self.next_token();
+        Ok(data_type)
+    }
+
+    /// Parse VARCHAR data type length (`(n)`).
+    ///
+    /// # Returns
+    /// - `SQL data type` - in case of success.
+    /// - `Err`           - in case of failure.
+    fn parse_varchar_length(&mut self) -> Result<DataType> {
+        self.expect_token(&Token::LeftParen)?;
+        let length = self.parse_unsigned_integer("length")?;
+        self.expect_token(&Token::RightParen)?;
+
+        match u32::try_from(length.value) {
+            Ok(value) if value > 0 => Ok(DataType::Varchar(value)),
+            _ => {
+                let message = "VARCHAR length is out of range";
+                let kind = ErrorKind::Semantic;
+
+                Err(TorusError::new(kind, length.span, message))
+            }
+        }
+    }
+
+    /// Parse optional DECIMAL data type parameters (`(p)` or `(p, s)`).
+    ///
+    /// # Returns
+    /// - `SQL data type` - in case of success.
+    /// - `Err`           - in case of failure.
+    fn parse_decimal_parameters(&mut self) -> Result<DataType> {
+        if !self.consume_token(&Token::LeftParen)? {
+            return Ok(DataType::Decimal {
+                precision: None,
+                scale: 0,
+            });
+        }
+
+        let precision = self.parse_unsigned_integer("precision")?;
+
+        let scale = match self.consume_token(&Token::Comma)? {
+            true => Some(self.parse_unsigned_integer("scale")?),
+            false => None,
+        };
+
+        self.expect_token(&Token::RightParen)?;
+
+        if !(1..=MAX_DECIMAL_PRECISION).contains(&precision.value) {
+            let message = format!(
+                "DECIMAL precision must be between 1 and \
+                 {MAX_DECIMAL_PRECISION}"
+            );
+            let kind = ErrorKind::Semantic;
+
+            return Err(TorusError::new(kind, precision.span, message));
+        }
+
+        if let Some(scale) = scale
+            && scale.value > precision.value
+        {
+            let message = "DECIMAL scale must not exceed precision";
+            let kind = ErrorKind::Semantic;
+
+            return Err(TorusError::new(kind, scale.span, message));
+        }
+
+        Ok(DataType::Decimal {
+            precision: Some(precision.value as u8),
+            scale: scale.map_or(0, |scale| scale.value as u8),
+        })
+    }
+
+    /// Parse drop statement.
+    ///
+    /// # Returns
+    /// - `SQL statement` - in case of success.
+    /// - `Err`           - in case of failure.
+    pub(super) fn parse_drop(&mut self) -> Result<Statement> {
+        self.expect_keyword(Keyword::Drop)?;
+
+        match self.current_token()? {
+            // Handle DROP DATABASE statement.
+            Token::Keyword(Keyword::Database) => self.parse_drop_database(),
+            // Handle DROP TABLE statement.
+            Token::Keyword(Keyword::Table) => self.parse_drop_table(),
+            _ => Err(self.unexpected("DATABASE or TABLE")),
+        }
+    }
+
+    /// Parse drop database statement.
+    ///
+    /// # Returns
+    /// - `SQL statement` - in case of success.
+    /// - `Err`           - in case of failure.
+    fn parse_drop_database(&mut self) -> Result<Statement> {
+        self.expect_keyword(Keyword::Database)?;
+
+        let if_exists = self.parse_if_exists()?;
+        let name = self.parse_identifier("database name")?;
+
+        Ok(Statement::DropDatabase { name, if_exists })
+    }
+
+    /// Parse drop table statement.
+    ///
+    /// # Returns
+    /// - `SQL statement` - in case of success.
+    /// - `Err`           - in case of failure.
+    fn parse_drop_table(&mut self) -> Result<Statement> {
+        self.expect_keyword(Keyword::Table)?;
+
+        let if_exists = self.parse_if_exists()?;
+        let names = self.parse_comma_separated(|parser| {
+            parser.parse_identifier("table name")
+        })?;
+        let behavior = self.parse_drop_behavior()?;
+
+        Ok(Statement::DropTable {
+            names,
+            if_exists,
+            behavior,
+        })
+    }
+
+    /// Parse alter statement.
+    ///
+    /// # Returns
+    /// - `SQL statement` - in case of success.
+    /// - `Err`           - in case of failure.
+    pub(super) fn parse_alter(&mut self) -> Result<Statement> {
+        self.expect_keyword(Keyword::Alter)?;
+
+        match self.current_token()? {
+            // Handle ALTER DATABASE statement.
+            Token::Keyword(Keyword::Database) => self.parse_alter_database(),
+            // Handle ALTER TABLE statement.
+            Token::Keyword(Keyword::Table) => self.parse_alter_table(),
+            _ => Err(self.unexpected("DATABASE or TABLE")),
+        }
+    }
+
+    /// Parse alter database statement.
+    ///
+    /// # Returns
+    /// - `SQL statement` - in case of success.
+    /// - `Err`           - in case of failure.
+    fn parse_alter_database(&mut self) -> Result<Statement> {
+        self.expect_keyword(Keyword::Database)?;
+        let name = self.parse_identifier("database name")?;
+
+        self.expect_keyword(Keyword::Rename)?;
+        self.expect_keyword(Keyword::To)?;
+
+        let new_name = self.parse_identifier("database name")?;
+        let action = AlterDatabaseAction::RenameTo(new_name);
+
+        Ok(Statement::AlterDatabase { name, action })
+    }
+
+    /// Parse alter table statement.
+    ///
+    /// # Returns
+    /// - `SQL statement` - in case of success.
+    /// - `Err`           - in case of failure.
+    fn parse_alter_table(&mut self) -> Result<Statement> {
+        self.expect_keyword(Keyword::Table)?;
+        let name = self.parse_identifier("table name")?;
+
+        let action = match self.current_token()? {
+            Token::Keyword(Keyword::Add) => {
+                self.next_token();
+                self.parse_alter_table_add()?
+            }
+            Token::Keyword(Keyword::Drop) => {
+                self.next_token();
+                self.parse_alter_table_drop()?
+            }
+            Token::Keyword(Keyword::Rename) => {
+                self.next_token();
+                self.parse_alter_table_rename()?
+            }
+            Token::Keyword(Keyword::Alter) => {
+                self.next_token();
+                self.parse_alter_column()?
+            }
+            _ => return Err(self.unexpected("ADD, DROP, RENAME or ALTER")),
+        };
+
+        Ok(Statement::AlterTable { name, action })
+    }
+
+    /// Parse `ADD [COLUMN] column | ADD constraint` action.
+    ///
+    /// # Returns
+    /// - `ALTER TABLE action` - in case of success.
+    /// - `Err`                - in case of failure.
+    fn parse_alter_table_add(&mut self) -> Result<AlterTableAction> {
+        if is_table_constraint(self.current_token()?) {
+            let constraint = self.parse_table_constraint()?;
+            return Ok(AlterTableAction::AddConstraint(constraint));
+        }
+
+        self.consume_keyword(Keyword::Column)?;
+        Ok(AlterTableAction::AddColumn(self.parse_column_def()?))
+    }
+
+    /// Parse `DROP [COLUMN] column | DROP CONSTRAINT name` action.
+    ///
+    /// # Returns
+    /// - `ALTER TABLE action` - in case of success.
+    /// - `Err`                - in case of failure.
+    fn parse_alter_table_drop(&mut self) -> Result<AlterTableAction> {
+        if self.consume_keyword(Keyword::Constraint)? {
+            let name = self.parse_identifier("constraint name")?;
+            let behavior = self.parse_drop_behavior()?;
+
+            return Ok(AlterTableAction::DropConstraint { name, behavior });
+        }
+
+        self.consume_keyword(Keyword::Column)?;
+
+        let name = self.parse_identifier("column name")?;
+        let behavior = self.parse_drop_behavior()?;
+
+        Ok(AlterTableAction::DropColumn { name, behavior })
+    }
+
+    /// Parse `RENAME TO name | RENAME [COLUMN] column TO name` action.
+    ///
+    /// # Returns
+    /// - `ALTER TABLE action` - in case of success.
+    /// - `Err`                - in case of failure.
+    fn parse_alter_table_rename(&mut self) -> Result<AlterTableAction> {
+        if self.consume_keyword(Keyword::To)? {
+            let new_name = self.parse_identifier("table name")?;
+            return Ok(AlterTableAction::RenameTo(new_name));
+        }
+
+        self.consume_keyword(Keyword::Column)?;
+        let name = self.parse_identifier("column name")?;
+
+        self.expect_keyword(Keyword::To)?;
+        let new_name = self.parse_identifier("column name")?;
+
+        Ok(AlterTableAction::RenameColumn { name, new_name })
+    }
+
+    /// Parse `ALTER [COLUMN] column ...` action.
+    ///
+    /// # Returns
+    /// - `ALTER TABLE action` - in case of success.
+    /// - `Err`                - in case of failure.
+    fn parse_alter_column(&mut self) -> Result<AlterTableAction> {
+        self.consume_keyword(Keyword::Column)?;
+        let name = self.parse_identifier("column name")?;
+
+        match self.current_token()? {
+            // Handle TYPE data_type.
+            Token::Keyword(Keyword::Type) => {
+                self.next_token();
+                let data_type = self.parse_data_type()?;
+
+                Ok(AlterTableAction::AlterColumnType { name, data_type })
+            }
+            Token::Keyword(Keyword::Set) => {
+                self.next_token();
+
+                match self.current_token()? {
+                    // Handle SET DEFAULT expr.
+                    Token::Keyword(Keyword::Default) => {
+                        self.next_token();
+                        let default = self.parse_constant_expr("DEFAULT")?;
+
+                        Ok(AlterTableAction::SetColumnDefault { name, default })
+                    }
+                    // Handle SET DATA TYPE data_type.
+                    Token::Keyword(Keyword::Data) => {
+                        self.next_token();
+                        self.expect_keyword(Keyword::Type)?;
+                        let data_type = self.parse_data_type()?;
+
+                        Ok(AlterTableAction::AlterColumnType {
+                            name,
+                            data_type,
+                        })
+                    }
+                    _ => Err(self.unexpected("DEFAULT or DATA TYPE")),
+                }
+            }
+            // Handle DROP NOT NULL.
+            Token::Keyword(Keyword::Drop) => {
+                self.next_token();
+                self.expect_keyword(Keyword::Not)?;
+                self.expect_keyword(Keyword::Null)?;
+
+                Ok(AlterTableAction::DropColumnNotNull(name))
+            }
+            _ => Err(self.unexpected("TYPE, SET or DROP")),
+        }
+    }
+
+    /// Parse truncate statement.
+    ///
+    /// # Returns
+    /// - `SQL statement` - in case of success.
+    /// - `Err`           - in case of failure.
+    pub(super) fn parse_truncate(&mut self) -> Result<Statement> {
+        self.expect_keyword(Keyword::Truncate)?;
+        self.consume_keyword(Keyword::Table)?;
+
+        let names = self.parse_comma_separated(|parser| {
+            parser.parse_identifier("table name")
+        })?;
+
+        Ok(Statement::Truncate { names })
+    }
+
+    /// Parse use statement.
+    ///
+    /// # Returns
+    /// - `SQL statement` - in case of success.
+    /// - `Err`           - in case of failure.
+    pub(super) fn parse_use(&mut self) -> Result<Statement> {
+        self.expect_keyword(Keyword::Use)?;
+
+        let name = self.parse_identifier("database name")?;
+        Ok(Statement::UseDatabase { name })
+    }
+
+    /// Parse optional `CASCADE | RESTRICT` clause.
+    ///
+    /// # Returns
+    /// - `Drop behavior` - in case of success.
+    /// - `Err`           - in case of failure.
+    fn parse_drop_behavior(&mut self) -> Result<DropBehavior> {
+        let behavior = match self.current_token()? {
+            Token::Keyword(Keyword::Cascade) => DropBehavior::Cascade,
+            Token::Keyword(Keyword::Restrict) => DropBehavior::Restrict,
+            _ => return Ok(DropBehavior::default()),
+        };
+
+        self.next_token();
+        Ok(behavior)
+    }
+
+    /// Parse optional `IF NOT EXISTS` clause.
+    ///
+    /// # Returns
+    /// - `true`  - if clause is present.
+    /// - `false` - otherwise.
+    /// - `Err`   - in case of failure.
+    fn parse_if_not_exists(&mut self) -> Result<bool> {
+        // IF is non-reserved, so it can be a name as well.
+        if *self.current_token()? != Token::Keyword(Keyword::If)
+            || *self.peek_token()? != Token::Keyword(Keyword::Not)
+        {
+            return Ok(false);
+        }
+
+        self.expect_keyword(Keyword::If)?;
+        self.expect_keyword(Keyword::Not)?;
+        self.expect_keyword(Keyword::Exists)?;
+
+        Ok(true)
+    }
+
+    /// Parse optional `IF EXISTS` clause.
+    ///
+    /// # Returns
+    /// - `true`  - if clause is present.
+    /// - `false` - otherwise.
+    /// - `Err`   - in case of failure.
+    fn parse_if_exists(&mut self) -> Result<bool> {
+        // IF is non-reserved, so it can be a name as well.
+        if *self.current_token()? != Token::Keyword(Keyword::If)
+            || *self.peek_token()? != Token::Keyword(Keyword::Exists)
+        {
+            return Ok(false);
+        }
+
+        self.expect_keyword(Keyword::If)?;
+        self.expect_keyword(Keyword::Exists)?;
+
+        Ok(true)
+    }
+}
+
+/// Check whether token starts table-level constraint.
+///
+/// # Parameters
+/// - `token` - given SQL token to check.
+///
+/// # Returns
+/// - `true`  - if token starts table-level constraint.
+/// - `false` - otherwise.
+fn is_table_constraint(token: &Token) -> bool {
+    matches!(
+        token,
+        Token::Keyword(
+            Keyword::Constraint
+                | Keyword::Primary
+                | Keyword::Unique
+                | Keyword::Check
+                | Keyword::Foreign
+        )
+    )
+}
+
+/// Check that table constraint refers only to existing columns.
+///
+/// # Parameters
+/// - `columns`    - given table columns definitions.
+/// - `constraint` - given table constraint.
+/// - `span`       - given position of constraint.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - otherwise.
+fn check_constraint_columns(
+    columns: &[ColumnDef],
+    constraint: &Constraint,
+    span: Span,
+) -> Result<()> {
+    let exists = |name: &str| columns.iter().any(|column| column.name == name);
+
+    let unknown = match &constraint.kind {
+        ConstraintKind::PrimaryKey(names)
+        | ConstraintKind::Unique(names)
+        | ConstraintKind::ForeignKey { columns: names, .. } => {
+            names.iter().map(String::as_str).find(|name| !exists(name))
+        }
+        // Qualified references are left to catalog.
+        ConstraintKind::Check(expr) => find_unknown_column(expr, &exists),
+    };
+
+    if let Some(name) = unknown {
+        let message =
+            format!("column \"{name}\" named in constraint does not exist");
+        let kind = ErrorKind::Semantic;
+
+        return Err(TorusError::new(kind, span, message));
+    }
+
+    Ok(())
+}
+
+/// Find unqualified column reference to missing column.
+///
+/// # Parameters
+/// - `expr`   - given SQL expression to check.
+/// - `exists` - given check whether column exists.
+///
+/// # Returns
+/// - Column name - if such reference is found.
+/// - `None`      - otherwise.
+fn find_unknown_column<'a>(
+    expr: &'a Expr,
+    exists: &impl Fn(&str) -> bool,
+) -> Option<&'a str> {
+    if let Expr::Column { table: None, name } = expr
+        && !exists(name)
+    {
+        return Some(name);
+    }
+
+    expr.children()
+        .into_iter()
+        .find_map(|child| find_unknown_column(child, exists))
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::compiler::{
+        lexer::Lexer,
+        parser::{
+            Parser,
+            ast::{
+                AlterDatabaseAction, AlterTableAction, ColumnDef, Constraint,
+                ConstraintKind, DataType, DropBehavior, Expr, Literal,
+                ReferentialAction, Statement,
+            },
+        },
+    };
+    use torussql_sdk::error::ErrorKind;
+
+    fn parse(input: &str) -> Statement {
+        Parser::new(Lexer::new(input)).parse().unwrap()
+    }
+
+    #[test]
+    fn test_create_database_if_not_exists() {
+        let statement = parse("CREATE DATABASE IF NOT EXISTS db");
+        let correct_statement = Statement::CreateDatabase {
+            name: "db".to_string(),
+            if_not_exists: true,
+        };
+
+        assert_eq!(statement, correct_statement);
+
+        // Check that IF can still be used as database name.
+        let statement = parse("CREATE DATABASE if");
+        let correct_statement = Statement::CreateDatabase {
+            name: "if".to_string(),
+            if_not_exists: false,
+        };
+
+        assert_eq!(statement, correct_statement);
+    }
+
+    #[test]
+    fn test_drop_database() {
+        let inputs = [
+            ("DROP DATABASE db", false),
+            ("drop database if exists db", true),
+        ];
+
+        for (input, if_exists) in inputs {
+            let correct_statement = Statement::DropDatabase {
+                name: "db".to_string(),
+                if_exists,
+            };
+
+            assert_eq!(parse(input), correct_statement);
+        }
+    }
+
+    #[test]
+    fn test_alter_database() {
+        let statement = parse("ALTER DATABASE old RENAME TO \"New\"");
+        let correct_statement = Statement::AlterDatabase {
+            name: "old".to_string(),
+            action: AlterDatabaseAction::RenameTo("New".to_string()),
+        };
+
+        assert_eq!(statement, correct_statement);
+    }
+
+    #[test]
+    fn test_use_database() {
+        let statement = parse("USE db");
+        let correct_statement = Statement::UseDatabase {
+            name: "db".to_string(),
+        };
+
+        assert_eq!(statement, correct_statement);
+    }
+
+    #[test]
+    fn test_create_table() {
+        let input = "CREATE TABLE IF NOT EXISTS users (
+            id BIGINT NOT NULL,
+            name VARCHAR(255) NOT NULL DEFAULT 'anonymous',
+            score DOUBLE PRECISION DEFAULT -1.5,
+            balance DECIMAL(10, 2) NULL DEFAULT 0,
+            active BOOLEAN DEFAULT TRUE,
+            bio TEXT,
+            born DATE,
+            wakes TIME,
+            created TIMESTAMP,
+            ttl INTERVAL,
+            avatar BLOB,
+            level SMALLINT,
+            rank INT,
+            ratio REAL,
+            amount NUMERIC,
+            nick CHARACTER VARYING(16)
+        )";
+
+        let column = |name: &str, data_type, not_null, default| ColumnDef {
+            name: name.to_string(),
+            data_type,
+            not_null,
+            default,
+            constraints: Vec::new(),
+        };
+        let literal = |literal| Some(Expr::Literal(literal));
+
+        let columns = vec![
+            column("id", DataType::BigInt, true, None),
+            column(
+                "name",
+                DataType::Varchar(255),
+                true,
+                literal(Literal::String("anonymous".to_string())),
+            ),
+            column(
+                "score",
+                DataType::Double,
+                false,
+                literal(Literal::Float(-1.5)),
+            ),
+            column(
+                "balance",
+                DataType::Decimal {
+                    precision: Some(10),
+                    scale: 2,
+                },
+                false,
+                literal(Literal::Integer(0)),
+            ),
+            column(
+                "active",
+                DataType::Boolean,
+                false,
+                literal(Literal::Boolean(true)),
+            ),
+            column("bio", DataType::Text, false, None),
+            column("born", DataType::Date, false, None),
+            column("wakes", DataType::Time, false, None),
+            column("created", DataType::Timestamp, false, None),
+            column("ttl", DataType::Interval, false, None),
+            column("avatar", DataType::Blob, false, None),
+            column("level", DataType::SmallInt, false, None),
+            column("rank", DataType::Integer, false, None),
+            column("ratio", DataType::Real, false, None),
+            column(
+                "amount",
+                DataType::Decimal {
+                    precision: None,
+                    scale: 0,
+                },
+                false,
+                None,
+            ),
+            column("nick", DataType::Varchar(16), false, None),
+        ];
+
+        let correct_statement = Statement::CreateTable {
+            name: "users".to_string(),
+            if_not_exists: true,
+            columns,
+            constraints: Vec::new(),
+        };
+
+        assert_eq!(parse(input), correct_statement);
+    }
+
+    #[test]
+    fn test_create_table_constraints() {
+        let input = "CREATE TABLE orders (
+            id INT CONSTRAINT orders_pk PRIMARY KEY,
+            code TEXT NOT NULL UNIQUE,
+            user_id INT REFERENCES users ON DELETE CASCADE,
+            item_id INT,
+            active BOOLEAN CHECK (TRUE),
+            CONSTRAINT item_fk FOREIGN KEY (item_id, code)
+                REFERENCES items (id, code)
+                ON UPDATE SET NULL ON DELETE RESTRICT,
+            UNIQUE (item_id, user_id)
+        )";
+
+        let Statement::CreateTable {
+            columns,
+            constraints,
+            ..
+        } = parse(input)
+        else {
+            panic!("expected CREATE TABLE statement");
+        };
+
+        let column_constraints: Vec<_> = columns
+            .into_iter()
+            .map(|column| column.constraints)
+            .collect();
+
+        let correct_column_constraints = vec![
+            vec![Constraint {
+                name: Some("orders_pk".to_string()),
+                kind: ConstraintKind::PrimaryKey(vec!["id".to_string()]),
+            }],
+            vec![Constraint {
+                name: None,
+                kind: ConstraintKind::Unique(vec!["code".to_string()]),
+            }],
+            vec![Constraint {
+                name: None,
+                kind: ConstraintKind::ForeignKey {
+                    columns: vec!["user_id".to_string()],
+                    table: "users".to_string(),
+                    referred_columns: Vec::new(),
+                    on_delete: ReferentialAction::Cascade,
+                    on_update: ReferentialAction::NoAction,
+                },
+            }],
+            Vec::new(),
+            vec![Constraint {
+                name: None,
+                kind: ConstraintKind::Check(Expr::Literal(Literal::Boolean(
+                    true,
+                ))),
+            }],
+        ];
+
+        let correct_constraints = vec![
+            Constraint {
+                name: Some("item_fk".to_string()),
+                kind: ConstraintKind::ForeignKey {
+                    columns: vec!["item_id".to_string(), "code".to_string()],
+                    table: "items".to_string(),
+                    referred_columns: vec![
+                        "id".to_string(),
+                        "code".to_string(),
+                    ],
+                    on_delete: ReferentialAction::Restrict,
+                    on_update: ReferentialAction::SetNull,
+                },
+            },
+            Constraint {
+                name: None,
+                kind: ConstraintKind::Unique(vec![
+                    "item_id".to_string(),
+                    "user_id".to_string(),
+                ]),
+            },
+        ];
+
+        assert_eq!(column_constraints, correct_column_constraints);
+        assert_eq!(constraints, correct_constraints);
+    }
+
+    #[test]
+    fn test_alter_table() {
+        let column = ColumnDef {
+            name: "age".to_string(),
+            data_type: DataType::SmallInt,
+            not_null: true,
+            default: None,
+            constraints: Vec::new(),
+        };
+        let unique = Constraint {
+            name: Some("u".to_string()),
+            kind: ConstraintKind::Unique(vec!["a".to_string()]),
+        };
+
+        let inputs = [
+            (
+                "ALTER TABLE t ADD COLUMN age SMALLINT NOT NULL",
+                AlterTableAction::AddColumn(column),
+            ),
+            (
+                "ALTER TABLE t DROP COLUMN a CASCADE",
+                AlterTableAction::DropColumn {
+                    name: "a".to_string(),
+                    behavior: DropBehavior::Cascade,
+                },
+            ),
+            (
+                "ALTER TABLE t RENAME a TO b",
+                AlterTableAction::RenameColumn {
+                    name: "a".to_string(),
+                    new_name: "b".to_string(),
+                },
+            ),
+            (
+                "ALTER TABLE t ALTER COLUMN a TYPE TEXT",
+                AlterTableAction::AlterColumnType {
+                    name: "a".to_string(),
+                    data_type: DataType::Text,
+                },
+            ),
+            (
+                "ALTER TABLE t ALTER a SET DATA TYPE BIGINT",
+                AlterTableAction::AlterColumnType {
+                    name: "a".to_string(),
+                    data_type: DataType::BigInt,
+                },
+            ),
+            (
+                "ALTER TABLE t ALTER COLUMN a SET DEFAULT 1",
+                AlterTableAction::SetColumnDefault {
+                    name: "a".to_string(),
+                    default: Expr::Literal(Literal::Integer(1)),
+                },
+            ),
+            (
+                "ALTER TABLE t ALTER COLUMN a DROP NOT NULL",
+                AlterTableAction::DropColumnNotNull("a".to_string()),
+            ),
+            (
+                "ALTER TABLE t ADD CONSTRAINT u UNIQUE (a)",
+                AlterTableAction::AddConstraint(unique),
+            ),
+            (
+                "ALTER TABLE t DROP CONSTRAINT u",
+                AlterTableAction::DropConstraint {
+                    name: "u".to_string(),
+                    behavior: DropBehavior::Restrict,
+                },
+            ),
+            (
+                "ALTER TABLE t RENAME TO t2",
+                AlterTableAction::RenameTo("t2".to_string()),
+            ),
+        ];
+
+        for (input, action) in inputs {
+            let correct_statement = Statement::AlterTable {
+                name: "t".to_string(),
+                action,
+            };
+
+            assert_eq!(parse(input), correct_statement, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_drop_table_and_truncate() {
+        let statement = parse("DROP TABLE IF EXISTS a, b CASCADE");
+        let correct_statement = Statement::DropTable {
+            names: vec!["a".to_string(), "b".to_string()],
+            if_exists: true,
+            behavior: DropBehavior::Cascade,
+        };
+
+        assert_eq!(statement, correct_statement);
+
+        let inputs = ["TRUNCATE a, b", "TRUNCATE TABLE a, b"];
+
+        for input in inputs {
+            let correct_statement = Statement::Truncate {
+                names: vec!["a".to_string(), "b".to_string()],
+            };
+
+            assert_eq!(parse(input), correct_statement, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_invalid_table_statements() {
+        let inputs = [
+            "ALTER TABLE t",
+            "ALTER TABLE t ADD",
+            "ALTER TABLE t RENAME COLUMN a b",
+            "ALTER TABLE t ALTER COLUMN a SET NULL",
+            "ALTER TABLE t ALTER COLUMN a DROP NULL",
+            "ALTER TABLE t DROP CONSTRAINT",
+            "DROP TABLE",
+            "DROP TABLE a,",
+            "TRUNCATE",
+        ];
+
+        for input in inputs {
+            let mut parser = Parser::new(Lexer::new(input));
+            let error = parser.parse_script().unwrap_err().error;
+
+            assert_eq!(error.kind, ErrorKind::Syntax, "{input}");
+        }
+
+        let inputs = [
+            "ALTER TABLE t ALTER COLUMN a SET DEFAULT count(*)",
+            "ALTER TABLE t ALTER COLUMN a SET DEFAULT (SELECT 1)",
+        ];
+
+        for input in inputs {
+            let mut parser = Parser::new(Lexer::new(input));
+            let error = parser.parse_script().unwrap_err().error;
+
+            assert_eq!(error.kind, ErrorKind::Semantic, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_invalid_create_table() {
+        let inputs = [
+            ("CREATE TABLE t ()", ErrorKind::Syntax),
+            ("CREATE TABLE t (a)", ErrorKind::Syntax),
+            ("CREATE TABLE t (a FOO)", ErrorKind::Syntax),
+            ("CREATE TABLE t (a INT,)", ErrorKind::Syntax),
+            ("CREATE TABLE t (a VARCHAR)", ErrorKind::Syntax),
+            ("CREATE TABLE t (a VARCHAR(0))", ErrorKind::Semantic),
+            ("CREATE TABLE t (a DECIMAL(39))", ErrorKind::Semantic),
+            ("CREATE TABLE t (a DECIMAL(5, 6))", ErrorKind::Semantic),
+            ("CREATE TABLE t (a INT NOT NULL NULL)", ErrorKind::Semantic),
+            ("CREATE TABLE t (a INT NULL NOT NULL)", ErrorKind::Semantic),
+            (
+                "CREATE TABLE t (a INT DEFAULT 1 DEFAULT 2)",
+                ErrorKind::Semantic,
+            ),
+            ("CREATE TABLE t (a INT PRIMARY)", ErrorKind::Syntax),
+            ("CREATE TABLE t (a INT CONSTRAINT c)", ErrorKind::Syntax),
+            (
+                "CREATE TABLE t (a INT REFERENCES u ON DROP)",
+                ErrorKind::Syntax,
+            ),
+            ("CREATE TABLE t (a INT, FOREIGN KEY (a))", ErrorKind::Syntax),
+            (
+                "CREATE TABLE t (a INT PRIMARY KEY, PRIMARY KEY (a))",
+                ErrorKind::Semantic,
+            ),
+            ("CREATE TABLE t (a INT, UNIQUE (b))", ErrorKind::Semantic),
+            ("CREATE TABLE t (a INT, CHECK (c > 0))", ErrorKind::Semantic),
+            ("CREATE TABLE t (a INT CHECK (b > 0))", ErrorKind::Semantic),
+            ("CREATE TABLE t (a INT DEFAULT sum(a))", ErrorKind::Semantic),
+            (
+                "CREATE TABLE t (a INT DEFAULT rank() OVER ())",
+                ErrorKind::Semantic,
+            ),
+            (
+                "CREATE TABLE t (a INT CHECK (a > (SELECT 1)))",
+                ErrorKind::Semantic,
+            ),
+            (
+                "CREATE TABLE t (a INT, CHECK (EXISTS (SELECT 1)))",
+                ErrorKind::Semantic,
+            ),
+            (
+                "CREATE TABLE t (a INT, PRIMARY KEY (a, a))",
+                ErrorKind::Semantic,
+            ),
+            ("CREATE TABLE t (a INT, UNIQUE (a, a))", ErrorKind::Semantic),
+            (
+                "CREATE TABLE t (a INT, FOREIGN KEY (a, a) REFERENCES u)",
+                ErrorKind::Semantic,
+            ),
+            (
+                "CREATE TABLE t (a INT, CHECK (a > 0 AND abs(b) < 1))",
+                ErrorKind::Semantic,
+            ),
+            ("CREATE TABLE t (a INT, a TEXT)", ErrorKind::Semantic),
+            (
+                "CREATE TABLE t (a INT REFERENCES u (x, y))",
+                ErrorKind::Semantic,
+            ),
+            (
+                "CREATE TABLE t (a INT REFERENCES u \
+                 ON DELETE CASCADE ON DELETE RESTRICT)",
+                ErrorKind::Semantic,
+            ),
+        ];
+
+        for (input, kind) in inputs {
+            let mut parser = Parser::new(Lexer::new(input));
+            let error = parser.parse().unwrap_err();
+
+            assert_eq!(error.kind, kind, "{input}");
+        }
+
+        // CHECK may precede columns it refers to.
+        let inputs = [
+            "CREATE TABLE t (CHECK (a > t.b), a INT)",
+            "CREATE TABLE t (a INT CHECK (a < b), b INT)",
+        ];
+
+        for input in inputs {
+            assert!(Parser::new(Lexer::new(input)).parse().is_ok(), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_invalid_database_statements() {
+        let inputs = [
+            "CREATE DATABASE IF NOT db",
+            "DROP DATABASE IF NOT EXISTS db",
+            "ALTER DATABASE db RENAME db2",
+            "USE",
+        ];
+
+        for input in inputs {
+            let mut parser = Parser::new(Lexer::new(input));
+            let error = parser.parse_script().unwrap_err().error;
+
+            assert_eq!(error.kind, ErrorKind::Syntax, "{input}");
+        }
+    }
+}
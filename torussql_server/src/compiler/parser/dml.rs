@@ -0,0 +1,327 @@
+// Project name: TorusSQL.
+// Description: Relational database management system.
+// Licence: GPL-3.0.
+// Author: Alexander (@alkuzin).
+
+//! SQL DML related statements parsing declarations.
+
+use crate::compiler::{
+    lexer::token::{Keyword, Token},
+    parser::{
+        Parser,
+        ast::{Assignment, Expr, InsertSource, Statement},
+        query::column_count,
+    },
+};
+use torussql_sdk::error::{ErrorKind, Result, TorusError};
+
+impl Parser<'_> {
+    /// Parse insert statement.
+    ///
+    /// # Returns
+    /// - `SQL statement` - in case of success.
+    /// - `Err`           - in case of failure.
+    pub(super) fn parse_insert(&mut self) -> Result<Statement> {
+        self.expect_keyword(Keyword::Insert)?;
+        self.expect_keyword(Keyword::Into)?;
+
+        let table = self.parse_identifier("table name")?;
+
+        let columns = match self.current_token()? {
+            Token::LeftParen => self.parse_column_list()?,
+            _ => Vec::new(),
+        };
+
+        let source = match self.current_token()? {
+            Token::Keyword(Keyword::Values) => {
+                InsertSource::Values(self.parse_values(columns.len())?)
+            }
+            Token::Keyword(Keyword::Select | Keyword::With)
+            | Token::LeftParen => {
+                let span = self.current_span()?;
+                let query = self.parse_query()?;
+                let span = span.merge(&self.previous_span);
+
+                // Width of query with wildcards is known only at runtime.
+                if let Some(count) = column_count(&query.body)
+                    && !columns.is_empty()
+                    && count != columns.len()
+                {
+                    let message = format!(
+                        "SELECT query has {count} columns, expected {}",
+                        columns.len()
+                    );
+                    let kind = ErrorKind::Semantic;
+
+                    return Err(TorusError::new(kind, span, message));
+                }
+
+                InsertSource::Query(Box::new(query))
+            }
+            _ => return Err(self.unexpected("VALUES or SELECT")),
+        };
+
+        Ok(Statement::Insert {
+            table,
+            columns,
+            source,
+        })
+    }
+
+    /// Parse `VALUES (...), (...)` clause.
+    ///
+    /// # Parameters
+    /// - `columns` - given number of columns to fill (0 if not specified).
+    ///
+    /// # Returns
+    /// - Rows of values - in case of success.
+    /// - `Err`          - in case of failure.
+    fn parse_values(&mut self, columns: usize) -> Result<Vec<Vec<Expr>>> {
+        self.expect_keyword(Keyword::Values)?;
+
+        // All rows must be as wide as column list or as the first row.
+        let mut width = (columns != 0).then_some(columns);
+
+        self.parse_comma_separated(|parser| {
+            let span = parser.current_span()?;
+            let row = parser.parse_parenthesized(|parser| {
+                parser.parse_row_expr("VALUES")
+            })?;
+            let expected = *width.get_or_insert(row.len());
+
+            if row.len() != expected {
+                let message = format!(
+                    "VALUES row has {} values, expected {expected}",
+                    row.len()
+                );
+                let kind = ErrorKind::Semantic;
+
+                return Err(TorusError::new(kind, span, message));
+            }
+
+            Ok(row)
+        })
+    }
+
+    /// Parse update statement.
+    ///
+    /// # Returns
+    /// - `SQL statement` - in case of success.
+    /// - `Err`           - in case of failure.
+    pub(super) fn parse_update(&mut self) -> Result<Statement> {
+        self.expect_keyword(Keyword::Update)?;
+
+        let table = self.parse_identifier("table name")?;
+        self.expect_keyword(Keyword::Set)?;
+
+        let mut assignments: Vec<Assignment> = Vec::new();
+
+        loop {
+            let span = self.current_span()?;
+            let column = self.parse_identifier("column name")?;
+
+            if assignments.iter().any(|other| other.column == column) {
+                let message =
+                    format!("multiple assignments to same column \"{column}\"");
+                let kind = ErrorKind::Semantic;
+
+                return Err(TorusError::new(kind, span, message));
+            }
+
+            self.expect_token(&Token::Equal)?;
+            let value = self.parse_row_expr("UPDATE")?;
+
+            assignments.push(Assignment { column, value });
+
+            if !self.consume_token(&Token::Comma)? {
+                break;
+            }
+        }
+
+        let selection = self.parse_where()?;
+
+        Ok(Statement::Update {
+            table,
+            assignments,
+            selection,
+        })
+    }
+
+    /// Parse delete statement.
+    ///
+    /// # Returns
+    /// - `SQL statement` - in case of success.
+    /// - `Err`           - in case of failure.
+    pub(super) fn parse_delete(&mut self) -> Result<Statement> {
+        self.expect_keyword(Keyword::Delete)?;
+        self.expect_keyword(Keyword::From)?;
+
+        let table = self.parse_identifier("table name")?;
+        let selection = self.parse_where()?;
+
+        Ok(Statement::Delete { table, selection })
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::compiler::{
+        lexer::Lexer,
+        parser::{
+            Parser,
+            ast::{
+                Assignment, BinaryOperator, Expr, FunctionArgs, InsertSource,
+                Literal, Query, Select, SelectItem, SetExpr, Statement,
+                TableRef,
+            },
+        },
+    };
+    use torussql_sdk::error::ErrorKind;
+
+    fn parse(input: &str) -> Statement {
+        Parser::new(Lexer::new(input)).parse().unwrap()
+    }
+
+    fn column(name: &str) -> Expr {
+        Expr::Column {
+            table: None,
+            name: name.to_string(),
+        }
+    }
+
+    fn integer(value: i64) -> Expr {
+        Expr::Literal(Literal::Integer(value))
+    }
+
+    fn equal(left: Expr, right: Expr) -> Expr {
+        Expr::BinaryOp {
+            left: Box::new(left),
+            op: BinaryOperator::Equal,
+            right: Box::new(right),
+        }
+    }
+
+    #[test]
+    fn test_insert_values() {
+        let statement = parse("INSERT INTO t (a, b) VALUES (1, 2), (3, now())");
+        let now = Expr::Function {
+            name: "now".to_string(),
+            args: FunctionArgs::List(Vec::new()),
+            distinct: false,
+            filter: None,
+            over: None,
+        };
+        let correct_statement = Statement::Insert {
+            table: "t".to_string(),
+            columns: vec!["a".to_string(), "b".to_string()],
+            source: InsertSource::Values(vec![
+                vec![integer(1), integer(2)],
+                vec![integer(3), now],
+            ]),
+        };
+
+        assert_eq!(statement, correct_statement);
+    }
+
+    #[test]
+    fn test_insert_select() {
+        let statement = parse("INSERT INTO t SELECT * FROM s WHERE a = 1");
+        let body = Select {
+            distinct: false,
+            projection: vec![SelectItem::Wildcard],
+            from: vec![TableRef::Table {
+                name: "s".to_string(),
+                alias: None,
+            }],
+            selection: Some(equal(column("a"), integer(1))),
+            group_by: Vec::new(),
+            having: None,
+            windows: Vec::new(),
+        };
+        let query = Query {
+            with: None,
+            body: SetExpr::Select(Box::new(body)),
+            order_by: Vec::new(),
+            limit: None,
+            offset: None,
+        };
+        let correct_statement = Statement::Insert {
+            table: "t".to_string(),
+            columns: Vec::new(),
+            source: InsertSource::Query(Box::new(query)),
+        };
+
+        assert_eq!(statement, correct_statement);
+
+        // Width of wildcard is checked against table columns later.
+        parse("INSERT INTO t (a) SELECT * FROM s");
+        parse("INSERT INTO t (a, b) SELECT 1, 2");
+    }
+
+    #[test]
+    fn test_update_and_delete() {
+        let statement = parse("UPDATE t SET a = a + 1, b = NULL WHERE id = 7");
+        let increment = Expr::BinaryOp {
+            left: Box::new(column("a")),
+            op: BinaryOperator::Add,
+            right: Box::new(integer(1)),
+        };
+        let correct_statement = Statement::Update {
+            table: "t".to_string(),
+            assignments: vec![
+                Assignment {
+                    column: "a".to_string(),
+                    value: increment,
+                },
+                Assignment {
+                    column: "b".to_string(),
+                    value: Expr::Literal(Literal::Null),
+                },
+            ],
+            selection: Some(equal(column("id"), integer(7))),
+        };
+
+        assert_eq!(statement, correct_statement);
+
+        let correct_statement = Statement::Delete {
+            table: "t".to_string(),
+            selection: None,
+        };
+
+        assert_eq!(parse("DELETE FROM t"), correct_statement);
+    }
+
+    #[test]
+    fn test_invalid_dml_statements() {
+        let inputs = [
+            ("INSERT t VALUES (1)", ErrorKind::Syntax),
+            ("INSERT INTO t", ErrorKind::Syntax),
+            ("INSERT INTO t VALUES ()", ErrorKind::Syntax),
+            ("INSERT INTO t VALUES (1), (1, 2)", ErrorKind::Semantic),
+            ("INSERT INTO t (a, b) VALUES (1)", ErrorKind::Semantic),
+            ("INSERT INTO t (a, a) VALUES (1, 2)", ErrorKind::Semantic),
+            ("INSERT INTO t () VALUES (1)", ErrorKind::Syntax),
+            ("INSERT INTO t (a) SELECT 1, 2", ErrorKind::Semantic),
+            (
+                "INSERT INTO t (a, b) SELECT a FROM s UNION SELECT b FROM u",
+                ErrorKind::Semantic,
+            ),
+            ("INSERT INTO t VALUES (sum(a))", ErrorKind::Semantic),
+            ("UPDATE t SET a", ErrorKind::Syntax),
+            ("UPDATE t SET a = 1, a = 2", ErrorKind::Semantic),
+            ("UPDATE t SET a = sum(b)", ErrorKind::Semantic),
+            ("UPDATE t SET a = rank() OVER ()", ErrorKind::Semantic),
+            ("DELETE t", ErrorKind::Syntax),
+            ("DELETE FROM t WHERE", ErrorKind::Syntax),
+            ("DELETE FROM t WHRE id = 1", ErrorKind::Syntax),
+            ("DELETE FROM t WHERE sum(a) > 1", ErrorKind::Semantic),
+        ];
+
+        for (input, kind) in inputs {
+            let mut parser = Parser::new(Lexer::new(input));
+            let error = parser.parse().unwrap_err();
+
+            assert_eq!(error.kind, kind, "{input}");
+        }
+    }
+}
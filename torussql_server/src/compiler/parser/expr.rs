@@ -0,0 +1,1188 @@
+// Project name: TorusSQL.
+// Description: Relational database management system.
+// Licence: GPL-3.0.
+// Author: Alexander (@alkuzin).
+
+//! SQL expressions parsing declarations.
+
+use crate::compiler::{
+    lexer::{
+        MAX_INTEGER_MAGNITUDE,
+        token::{Keyword, Token},
+    },
+    parser::{
+        Parser,
+        ast::{
+            BinaryOperator, DataType, Expr, FunctionArgs, Literal, Quantifier,
+            UnaryOperator, is_aggregate_function, is_window_function,
+        },
+        is_name,
+    },
+};
+use chrono::{NaiveDate, NaiveTime};
+use torussql_sdk::error::{ErrorKind, Result, Span, TorusError};
+
+/// SQL operators binding power enumeration (from the loosest to the tightest).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Precedence {
+    /// Not an operator.
+    Lowest,
+    /// `OR`.
+    Or,
+    /// `AND`.
+    And,
+    /// Prefix `NOT`.
+    Not,
+    /// `IS [NOT] NULL`.
+    Is,
+    /// `=`, `<>`, `<`, `<=`, `>`, `>=` (non-associative).
+    Comparison,
+    /// `[NOT] BETWEEN`, `[NOT] IN`, `[NOT] LIKE`, `[NOT] ILIKE`.
+    Like,
+    /// `||`.
+    Concat,
+    /// `+`, `-`.
+    Additive,
+    /// `*`, `/`, `%`.
+    Multiplicative,
+    /// Prefix `+`, `-`.
+    Unary,
+    /// `::`.
+    Cast,
+}
+
+impl Parser<'_> {
+    /// Parse SQL expression.
+    ///
+    /// # Returns
+    /// - `SQL expression` - in case of success.
+    /// - `Err`            - in case of failure.
+    pub(super) fn parse_expr(&mut self) -> Result<Expr> {
+        self.parse_subexpr(Precedence::Lowest)
+    }
+
+    /// Parse SQL expression with operators binding tighter than given one.
+    ///
+    /// # Parameters
+    /// - `precedence` - given binding power of enclosing operator.
+    ///
+    /// # Returns
+    /// - `SQL expression` - in case of success.
+    /// - `Err`            - in case of failure.
+    fn parse_subexpr(&mut self, precedence: Precedence) -> Result<Expr> {
+        self.parse_nested(|parser| {
+            let expr = parser.parse_prefix()?;
+            parser.parse_infixes(expr, precedence)
+        })
+    }
+
+    /// Parse rest of SQL expression which starts with already parsed operand.
+    ///
+    /// # Parameters
+    /// - `expr` - given first operand.
+    ///
+    /// # Returns
+    /// - `SQL expression` - in case of success.
+    /// - `Err`            - in case of failure.
+    pub(super) fn parse_expr_from(&mut self, expr: Expr) -> Result<Expr> {
+        self.parse_infixes(expr, Precedence::Lowest)
+    }
+
+    /// Parse infix operators binding tighter than given one.
+    ///
+    /// # Parameters
+    /// - `expr`       - given first operand.
+    /// - `precedence` - given binding power of enclosing operator.
+    ///
+    /// # Returns
+    /// - `SQL expression` - in case of success.
+    /// - `Err`            - in case of failure.
+    fn parse_infixes(
+        &mut self,
+        expr: Expr,
+        precedence: Precedence,
+    ) -> Result<Expr> {
+        let depth = self.depth;
+        let result = self.parse_infix_chain(expr, precedence);
+        self.depth = depth;
+
+        result
+    }
+
+    /// Parse chain of infix operators binding tighter than given one.
+    ///
+    /// # Parameters
+    /// - `expr`       - given first operand.
+    /// - `precedence` - given binding power of enclosing operator.
+    ///
+    /// # Returns
+    /// - `SQL expression` - in case of success.
+    /// - `Err`            - in case of failure.
+    fn parse_infix_chain(
+        &mut self,
+        mut expr: Expr,
+        precedence: Precedence,
+    ) -> Result<Expr> {
+        loop {
+            let next_precedence = self.next_precedence()?;
+
+            if next_precedence <= precedence {
+                return Ok(expr);
+            }
+
+            // Each operator nests previous operands one level deeper.
+            self.enter_nested()?;
+            expr = self.parse_infix(expr, next_precedence)?;
+        }
+    }
+
+    /// Get binding power of current infix operator.
+    ///
+    /// # Returns
+    /// - `Operator precedence`  - in case of success.
+    /// - `Precedence::Lowest`   - if current token is not an infix operator.
+    /// - `Err`                  - in case of failure.
+    fn next_precedence(&mut self) -> Result<Precedence> {
+        let precedence = match self.current_token()? {
+            Token::Keyword(Keyword::Or) => Precedence::Or,
+            Token::Keyword(Keyword::And) => Precedence::And,
+            Token::Keyword(Keyword::Is) => Precedence::Is,
+            Token::Equal
+            | Token::NotEqual
+            | Token::Less
+            | Token::LessEqual
+            | Token::Greater
+            | Token::GreaterEqual => Precedence::Comparison,
+            Token::Keyword(
+                Keyword::Between | Keyword::In | Keyword::Like | Keyword::Ilike,
+            ) => Precedence::Like,
+            // NOT is infix only as a part of NOT BETWEEN/IN/LIKE/ILIKE.
+            Token::Keyword(Keyword::Not) => match self.peek_token()? {
+                Token::Keyword(
+                    Keyword::Between
+                    | Keyword::In
+                    | Keyword::Like
+                    | Keyword::Ilike,
+                ) => Precedence::Like,
+                _ => Precedence::Lowest,
+            },
+            Token::Concat => Precedence::Concat,
+            Token::Plus | Token::Minus => Precedence::Additive,
+            Token::Asterisk | Token::Slash | Token::Percent => {
+                Precedence::Multiplicative
+            }
+            Token::DoubleColon => Precedence::Cast,
+            _ => Precedence::Lowest,
+        };
+
+        Ok(precedence)
+    }
+
+    /// Parse SQL expression that starts with prefix operator or operand.
+    ///
+    /// # Returns
+    /// - `SQL expression` - in case of success.
+    /// - `Err`            - in case of failure.
+    fn parse_prefix(&mut self) -> Result<Expr> {
+        let (op, precedence) = match self.current_token()? {
+            Token::Keyword(Keyword::Not) => {
+                (UnaryOperator::Not, Precedence::Not)
+            }
+            Token::Minus => (UnaryOperator::Minus, Precedence::Unary),
+            Token::Plus => (UnaryOperator::Plus, Precedence::Unary),
+            _ => return self.parse_primary(),
+        };
+
+        self.next_token();
+
+        // Smallest integer has no positive counterpart, so fold it eagerly.
+        if matches!(op, UnaryOperator::Minus)
+            && *self.current_token()? == Token::Integer(MAX_INTEGER_MAGNITUDE)
+            && *self.peek_token()? != Token::DoubleColon
+        {
+            self.next_token();
+
+            let expr = Expr::Literal(Literal::Integer(i64::MIN));
+            return self.parse_infixes(expr, precedence);
+        }
+
+        let expr = self.parse_subexpr(precedence)?;
+
+        // Fold negative numbers into literals.
+        let expr = match (op, expr) {
+            (UnaryOperator::Minus, Expr::Literal(Literal::Integer(value))) => {
+                Expr::Literal(Literal::Integer(-value))
+            }
+            (UnaryOperator::Minus, Expr::Literal(Literal::Float(value))) => {
+                Expr::Literal(Literal::Float(-value))
+            }
+            (op, expr) => Expr::UnaryOp {
+                op,
+                expr: Box::new(expr),
+            },
+        };
+
+        Ok(expr)
+    }
+
+    /// Parse SQL expression operand.
+    ///
+    /// # Returns
+    /// - `SQL expression` - in case of success.
+    /// - `Err`            - in case of failure.
+    fn parse_primary(&mut self) -> Result<Expr> {
+        // Handle scalar subquery.
+        if self.is_subquery_start()? {
+            return Ok(Expr::Subquery(self.parse_column_subquery()?));
+        }
+
+        // Handle typed literal (`DATE '2026-01-01'`).
+        if matches!(
+            self.current_token()?,
+            Token::Keyword(
+                Keyword::Date
+                    | Keyword::Time
+                    | Keyword::Timestamp
+                    | Keyword::Interval
+            )
+        ) && matches!(self.peek_token()?, Token::String(_))
+        {
+            return self.parse_typed_literal();
+        }
+
+        let literal = match self.current_token()? {
+            Token::Keyword(Keyword::Null) => Literal::Null,
+            Token::Keyword(Keyword::True) => Literal::Boolean(true),
+            Token::Keyword(Keyword::False) => Literal::Boolean(false),
+            Token::Integer(value) => match i64::try_from(*value) {
+                Ok(value) => Literal::Integer(value),
+                Err(_) => {
+                    let message = "integer literal is out of range";
+                    let span = self.current_span()?;
+                    let kind = ErrorKind::Lexical;
+
+                    return Err(TorusError::new(kind, span, message));
+                }
+            },
+            Token::Float(value) => Literal::Float(*value),
+            Token::String(value) => Literal::String(value.clone()),
+            Token::Blob(value) => Literal::Blob(value.clone()),
+            Token::BitString(value) => Literal::BitString(value.clone()),
+            Token::Placeholder(index) => {
+                let expr = Expr::Placeholder(*index);
+                self.next_token();
+
+                return Ok(expr);
+            }
+            Token::Keyword(Keyword::Exists) => {
+                self.next_token();
+                return Ok(Expr::Exists(self.parse_subquery()?));
+            }
+            Token::Keyword(Keyword::Case) => return self.parse_case(),
+            Token::Keyword(Keyword::Cast) => return self.parse_cast(),
+            // Handle parenthesized subexpression.
+            Token::LeftParen => {
+                self.next_token();
+                let expr = self.parse_expr()?;
+                self.expect_token(&Token::RightParen)?;
+
+                return Ok(expr);
+            }
+            token if is_name(token) => return self.parse_name_expr(),
+            _ => return Err(self.unexpected("expression")),
+        };
+
+        self.next_token();
+        Ok(Expr::Literal(literal))
+    }
+
+    /// Parse constant of given type written as string (`type 'value'`).
+    ///
+    /// # Returns
+    /// - `SQL expression` - in case of success.
+    /// - `Err`            - in case of failure.
+    fn parse_typed_literal(&mut self) -> Result<Expr> {
+        let data_type = self.parse_data_type()?;
+        let span = self.current_span()?;
+
+        let Token::String(value) = self.current_token()? else {
+            return Err(self.unexpected("string"));
+        };
+
+        let value = value.clone();
+        self.next_token();
+
+        if !is_valid_datetime(&data_type, &value) {
+            let message = format!("invalid date/time literal '{value}'");
+            return Err(TorusError::new(ErrorKind::Semantic, span, message));
+        }
+
+        Ok(Expr::TypedLiteral { data_type, value })
+    }
+
+    /// Parse `CASE [operand] WHEN x THEN y ... [ELSE z] END` expression.
+    ///
+    /// # Returns
+    /// - `SQL expression` - in case of success.
+    /// - `Err`            - in case of failure.
+    fn parse_case(&mut self) -> Result<Expr> {
+        self.expect_keyword(Keyword::Case)?;
+
+        let operand = match self.current_token()? {
+            Token::Keyword(Keyword::When) => None,
+            _ => Some(Box::new(self.parse_expr()?)),
+        };
+
+        let mut branches = Vec::new();
+
+        // At least one WHEN branch is required.
+        loop {
+            self.expect_keyword(Keyword::When)?;
+            let when = self.parse_expr()?;
+            self.expect_keyword(Keyword::Then)?;
+
+            branches.push((when, self.parse_expr()?));
+
+            if *self.current_token()? != Token::Keyword(Keyword::When) {
+                break;
+            }
+        }
+
+        let else_result = match self.consume_keyword(Keyword::Else)? {
+            true => Some(Box::new(self.parse_expr()?)),
+            false => None,
+        };
+
+        self.expect_keyword(Keyword::End)?;
+
+        Ok(Expr::Case {
+            operand,
+            branches,
+            else_result,
+        })
+    }
+
+    /// Parse `CAST(x AS type)` expression.
+    ///
+    /// # Returns
+    /// - `SQL expression` - in case of success.
+    /// - `Err`            - in case of failure.
+    fn parse_cast(&mut self) -> Result<Expr> {
+        self.expect_keyword(Keyword::Cast)?;
+        self.expect_token(&Token::LeftParen)?;
+
+        let expr = Box::new(self.parse_expr()?);
+        self.expect_keyword(Keyword::As)?;
+        let data_type = self.parse_data_type()?;
+
+        self.expect_token(&Token::RightParen)?;
+        Ok(Expr::Cast { expr, data_type })
+    }
+
+    /// Parse column reference or function call.
+    ///
+    /// # Returns
+    /// - `SQL expression` - in case of success.
+    /// - `Err`            - in case of failure.
+    fn parse_name_expr(&mut self) -> Result<Expr> {
+        let span = self.current_span()?;
+        let name = self.parse_identifier("column name")?;
+
+        match self.current_token()? {
+            // Handle conditional expressions that look like function calls.
+            Token::LeftParen if name == "coalesce" => {
+                let args = self.parse_parenthesized(Self::parse_expr)?;
+                Ok(Expr::Coalesce(args))
+            }
+            Token::LeftParen if name == "nullif" => {
+                self.next_token();
+                let left = Box::new(self.parse_expr()?);
+                self.expect_token(&Token::Comma)?;
+                let right = Box::new(self.parse_expr()?);
+                self.expect_token(&Token::RightParen)?;
+
+                Ok(Expr::NullIf { left, right })
+            }
+            // Handle function call.
+            Token::LeftParen => self.parse_function(name, span),
+            // Handle qualified column reference.
+            Token::Dot => {
+                self.next_token();
+
+                Ok(Expr::Column {
+                    table: Some(name),
+                    name: self.parse_identifier("column name")?,
+                })
+            }
+            _ => Ok(Expr::Column { table: None, name }),
+        }
+    }
+
+    /// Parse function call arguments & aggregate clauses.
+    ///
+    /// # Parameters
+    /// - `name` - given function name.
+    /// - `span` - given function name position.
+    ///
+    /// # Returns
+    /// - `SQL expression` - in case of success.
+    /// - `Err`            - in case of failure.
+    fn parse_function(&mut self, name: String, span: Span) -> Result<Expr> {
+        self.expect_token(&Token::LeftParen)?;
+
+        let (args, distinct) = match self.current_token()? {
+            Token::Asterisk => {
+                self.next_token();
+                (FunctionArgs::Wildcard, false)
+            }
+            Token::RightParen => (FunctionArgs::List(Vec::new()), false),
+            _ => {
+                let distinct = match self.current_token()? {
+                    Token::Keyword(Keyword::Distinct) => {
+                        self.next_token();
+                        true
+                    }
+                    Token::Keyword(Keyword::All) => {
+                        self.next_token();
+                        false
+                    }
+                    _ => false,
+                };
+
+                let args = self.parse_comma_separated(Self::parse_expr)?;
+                (FunctionArgs::List(args), distinct)
+            }
+        };
+
+        self.expect_token(&Token::RightParen)?;
+
+        // FILTER is non-reserved, so it must be followed by parenthesis.
+        let filter = match *self.current_token()?
+            == Token::Keyword(Keyword::Filter)
+            && *self.peek_token()? == Token::LeftParen
+        {
+            true => {
+                self.next_token();
+                self.expect_token(&Token::LeftParen)?;
+                self.expect_keyword(Keyword::Where)?;
+
+                let filter = self.parse_expr()?;
+                self.expect_token(&Token::RightParen)?;
+
+                Some(Box::new(filter))
+            }
+            false => None,
+        };
+
+        let over = self.parse_over()?;
+
+        let operands = match &args {
+            FunctionArgs::List(args) => args.as_slice(),
+            FunctionArgs::Wildcard => &[],
+        };
+        let nested_aggregate = operands
+            .iter()
+            .chain(filter.as_deref())
+            .any(Expr::contains_aggregate);
+        let nested_window = operands
+            .iter()
+            .chain(filter.as_deref())
+            .any(Expr::contains_window);
+
+        let message = if args == FunctionArgs::Wildcard && name != "count" {
+            format!("{name}(*) is not allowed, only COUNT(*)")
+        } else if (distinct || filter.is_some())
+            && !is_aggregate_function(&name)
+        {
+            format!("{name} is not an aggregate function")
+        } else if over.is_some()
+            && !is_aggregate_function(&name)
+            && !is_window_function(&name)
+        {
+            format!("{name} is not a window or aggregate function")
+        } else if over.is_none() && is_window_function(&name) {
+            format!("window function {name} requires an OVER clause")
+        } else if over.is_some() && distinct {
+            "DISTINCT is not allowed for window functions".to_string()
+        } else if over.is_none()
+            && is_aggregate_function(&name)
+            && nested_aggregate
+        {
+            "aggregate function calls cannot be nested".to_string()
+        } else if over.is_none() && nested_window {
+            "aggregate function calls cannot contain window function calls"
+                .to_string()
+        } else if nested_window {
+            "window function calls cannot be nested".to_string()
+        } else {
+            return Ok(Expr::Function {
+                name,
+                args,
+                distinct,
+                filter,
+                over,
+            });
+        };
+
+        Err(TorusError::new(ErrorKind::Semantic, span, message))
+    }
+
+    /// Parse SQL expression that continues with infix operator.
+    ///
+    /// # Parameters
+    /// - `left`       - given left operand.
+    /// - `precedence` - given binding power of current infix operator.
+    ///
+    /// # Returns
+    /// - `SQL expression` - in case of success.
+    /// - `Err`            - in case of failure.
+    fn parse_infix(
+        &mut self,
+        left: Expr,
+        precedence: Precedence,
+    ) -> Result<Expr> {
+        let left = Box::new(left);
+
+        let op = match self.current_token()? {
+            Token::Keyword(Keyword::Or) => BinaryOperator::Or,
+            Token::Keyword(Keyword::And) => BinaryOperator::And,
+            Token::Concat => BinaryOperator::Concat,
+            Token::Plus => BinaryOperator::Add,
+            Token::Minus => BinaryOperator::Subtract,
+            Token::Asterisk => BinaryOperator::Multiply,
+            Token::Slash => BinaryOperator::Divide,
+            Token::Percent => BinaryOperator::Modulo,
+            Token::Equal => BinaryOperator::Equal,
+            Token::NotEqual => BinaryOperator::NotEqual,
+            Token::Less => BinaryOperator::Less,
+            Token::LessEqual => BinaryOperator::LessEqual,
+            Token::Greater => BinaryOperator::Greater,
+            Token::GreaterEqual => BinaryOperator::GreaterEqual,
+            Token::DoubleColon => {
+                self.next_token();
+                let data_type = self.parse_data_type()?;
+
+                return Ok(Expr::Cast {
+                    expr: left,
+                    data_type,
+                });
+            }
+            Token::Keyword(Keyword::Is) => {
+                self.next_token();
+                let negated = self.consume_keyword(Keyword::Not)?;
+                self.expect_keyword(Keyword::Null)?;
+
+                return Ok(Expr::IsNull {
+                    expr: left,
+                    negated,
+                });
+            }
+            _ => return self.parse_like_infix(left),
+        };
+
+        self.next_token();
+
+        let quantifier = match self.current_token()? {
+            Token::Keyword(Keyword::Any | Keyword::Some) => {
+                Some(Quantifier::Any)
+            }
+            Token::Keyword(Keyword::All) => Some(Quantifier::All),
+            _ => None,
+        };
+
+        let expr = match quantifier {
+            Some(quantifier) if precedence == Precedence::Comparison => {
+                self.next_token();
+
+                Expr::Quantified {
+                    left,
+                    op,
+                    quantifier,
+                    subquery: self.parse_column_subquery()?,
+                }
+            }
+            _ => Expr::BinaryOp {
+                left,
+                op,
+                right: Box::new(self.parse_subexpr(precedence)?),
+            },
+        };
+
+        // Forbid chains like `a < b < c`.
+        if precedence == Precedence::Comparison
+            && self.next_precedence()? == Precedence::Comparison
+        {
+            let span = self.current_span()?;
+            let message = "comparison operators are non-associative";
+
+            return Err(TorusError::new(ErrorKind::Syntax, span, message));
+        }
+
+        Ok(expr)
+    }
+
+    /// Parse `[NOT] BETWEEN`, `[NOT] IN`, `[NOT] LIKE|ILIKE` operators.
+    ///
+    /// # Parameters
+    /// - `expr` - given left operand.
+    ///
+    /// # Returns
+    /// - `SQL expression` - in case of success.
+    /// - `Err`            - in case of failure.
+    fn parse_like_infix(&mut self, expr: Box<Expr>) -> Result<Expr> {
+        let negated = self.consume_keyword(Keyword::Not)?;
+
+        match self.current_token()? {
+            Token::Keyword(Keyword::Between) => {
+                self.next_token();
+
+                // Bounds can't contain AND, so it is a part of BETWEEN.
+                let low = Box::new(self.parse_subexpr(Precedence::Like)?);
+                self.expect_keyword(Keyword::And)?;
+                let high = Box::new(self.parse_subexpr(Precedence::Like)?);
+
+                Ok(Expr::Between {
+                    expr,
+                    negated,
+                    low,
+                    high,
+                })
+            }
+            Token::Keyword(Keyword::In) => {
+                self.next_token();
+
+                if self.is_subquery_start()? {
+                    return Ok(Expr::InSubquery {
+                        expr,
+                        negated,
+                        subquery: self.parse_column_subquery()?,
+                    });
+                }
+
+                let list = self.parse_parenthesized(Self::parse_expr)?;
+
+                Ok(Expr::InList {
+                    expr,
+                    negated,
+                    list,
+                })
+            }
+            Token::Keyword(keyword @ (Keyword::Like | Keyword::Ilike)) => {
+                let case_insensitive = *keyword == Keyword::Ilike;
+                self.next_token();
+
+                let pattern = Box::new(self.parse_subexpr(Precedence::Like)?);
+
+                let escape = match self.consume_keyword(Keyword::Escape)? {
+                    true => {
+                        Some(Box::new(self.parse_subexpr(Precedence::Like)?))
+                    }
+                    false => None,
+                };
+
+                Ok(Expr::Like {
+                    expr,
+                    negated,
+                    case_insensitive,
+                    pattern,
+                    escape,
+                })
+            }
+            _ => Err(self.unexpected("BETWEEN, IN, LIKE or ILIKE")),
+        }
+    }
+}
+
+/// Check whether string is valid value of date/time type.
+///
+/// # Parameters
+/// - `data_type` - given SQL data type.
+/// - `value`     - given string to check.
+///
+/// # Returns
+/// - `true`  - if value is valid or type is not date/time one.
+/// - `false` - otherwise.
+fn is_valid_datetime(data_type: &DataType, value: &str) -> bool {
+    const TIME_FORMATS: [&str; 2] = ["%H:%M:%S%.f", "%H:%M"];
+
+    match data_type {
+        DataType::Date => NaiveDate::parse_from_str(value, "%Y-%m-%d").is_ok(),
+        DataType::Time => TIME_FORMATS
+            .iter()
+            .any(|format| NaiveTime::parse_from_str(value, format).is_ok()),
+        // Time part of timestamp is optional.
+        DataType::Timestamp => match value.split_once([' ', 'T']) {
+            Some((date, time)) => {
+                is_valid_datetime(&DataType::Date, date)
+                    && is_valid_datetime(&DataType::Time, time)
+            }
+            None => is_valid_datetime(&DataType::Date, value),
+        },
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::compiler::{
+        lexer::{Lexer, token::Token},
+        parser::{
+            Parser,
+            ast::{
+                BinaryOperator, DataType, Expr, FunctionArgs, Literal,
+                Quantifier, SetExpr, UnaryOperator,
+            },
+        },
+    };
+    use torussql_sdk::error::ErrorKind;
+
+    fn parse(input: &str) -> Expr {
+        Parser::new(Lexer::new(input)).parse_expr().unwrap()
+    }
+
+    fn column(name: &str) -> Box<Expr> {
+        Box::new(Expr::Column {
+            table: None,
+            name: name.to_string(),
+        })
+    }
+
+    fn integer(value: i64) -> Box<Expr> {
+        Box::new(Expr::Literal(Literal::Integer(value)))
+    }
+
+    fn binary(
+        left: Box<Expr>,
+        op: BinaryOperator,
+        right: Box<Expr>,
+    ) -> Box<Expr> {
+        Box::new(Expr::BinaryOp { left, op, right })
+    }
+
+    #[test]
+    fn test_arithmetic_precedence() {
+        use BinaryOperator::{Add, Multiply, Subtract};
+
+        // a + b * c - d => (a + (b * c)) - d
+        let correct_expr = binary(
+            binary(
+                column("a"),
+                Add,
+                binary(column("b"), Multiply, column("c")),
+            ),
+            Subtract,
+            column("d"),
+        );
+
+        assert_eq!(parse("a + b * c - d"), *correct_expr);
+
+        // (a + b) * c
+        let correct_expr = binary(
+            binary(column("a"), Add, column("b")),
+            Multiply,
+            column("c"),
+        );
+
+        assert_eq!(parse("(a + b) * c"), *correct_expr);
+    }
+
+    #[test]
+    fn test_left_associativity() {
+        use BinaryOperator::{Concat, Divide, Or, Subtract};
+
+        let inputs = [
+            ("a - b - c", Subtract),
+            ("a / b / c", Divide),
+            ("a || b || c", Concat),
+            ("a OR b OR c", Or),
+        ];
+
+        for (input, op) in inputs {
+            let correct_expr =
+                binary(binary(column("a"), op, column("b")), op, column("c"));
+
+            assert_eq!(parse(input), *correct_expr, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_logical_precedence() {
+        use BinaryOperator::{And, Equal, Or};
+
+        // NOT a = 1 OR b AND c => (NOT (a = 1)) OR (b AND c)
+        let not = Box::new(Expr::UnaryOp {
+            op: UnaryOperator::Not,
+            expr: binary(column("a"), Equal, integer(1)),
+        });
+        let correct_expr =
+            binary(not, Or, binary(column("b"), And, column("c")));
+
+        assert_eq!(parse("NOT a = 1 OR b AND c"), *correct_expr);
+    }
+
+    #[test]
+    fn test_unary_minus() {
+        use BinaryOperator::Multiply;
+
+        // -a * b => (-a) * b
+        let minus = Box::new(Expr::UnaryOp {
+            op: UnaryOperator::Minus,
+            expr: column("a"),
+        });
+
+        assert_eq!(parse("-a * b"), *binary(minus, Multiply, column("b")));
+
+        // Negative numbers are folded into literals.
+        assert_eq!(parse("-5 * 2"), *binary(integer(-5), Multiply, integer(2)));
+
+        // Smallest integer is written only as negative literal.
+        let min = integer(i64::MIN);
+
+        assert_eq!(parse("-9223372036854775808"), *min);
+        assert_eq!(
+            parse("-9223372036854775808 * 2"),
+            *binary(min, Multiply, integer(2))
+        );
+
+        for input in ["9223372036854775808", "1 - 9223372036854775808"] {
+            let mut parser = Parser::new(Lexer::new(input));
+            let error = parser.parse_expr().unwrap_err();
+
+            assert_eq!(error.kind, ErrorKind::Lexical, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_predicates() {
+        use BinaryOperator::{Add, And, Concat, Equal};
+
+        // a + 1 BETWEEN 0 AND 10 AND b => ((a + 1) BETWEEN 0 AND 10) AND b
+        let between = Box::new(Expr::Between {
+            expr: binary(column("a"), Add, integer(1)),
+            negated: false,
+            low: integer(0),
+            high: integer(10),
+        });
+
+        assert_eq!(
+            parse("a + 1 BETWEEN 0 AND 10 AND b"),
+            *binary(between, And, column("b"))
+        );
+
+        // a = b IS NOT NULL => (a = b) IS NOT NULL
+        let correct_expr = Expr::IsNull {
+            expr: binary(column("a"), Equal, column("b")),
+            negated: true,
+        };
+
+        assert_eq!(parse("a = b IS NOT NULL"), correct_expr);
+
+        // a NOT IN (1, 2)
+        let correct_expr = Expr::InList {
+            expr: column("a"),
+            negated: true,
+            list: vec![*integer(1), *integer(2)],
+        };
+
+        assert_eq!(parse("a NOT IN (1, 2)"), correct_expr);
+
+        // a || b NOT ILIKE 'x!%' ESCAPE '!' => (a || b) NOT ILIKE ...
+        let string = |value: &str| {
+            Box::new(Expr::Literal(Literal::String(value.to_string())))
+        };
+        let correct_expr = Expr::Like {
+            expr: binary(column("a"), Concat, column("b")),
+            negated: true,
+            case_insensitive: true,
+            pattern: string("x!%"),
+            escape: Some(string("!")),
+        };
+
+        assert_eq!(parse("a || b NOT ILIKE 'x!%' ESCAPE '!'"), correct_expr);
+    }
+
+    #[test]
+    fn test_column_refs_and_functions() {
+        let function = |name: &str, args| Expr::Function {
+            name: name.to_string(),
+            args: FunctionArgs::List(args),
+            distinct: false,
+            filter: None,
+            over: None,
+        };
+        let column = Expr::Column {
+            table: Some("t".to_string()),
+            name: "name".to_string(),
+        };
+        let correct_expr =
+            function("upper", vec![column, function("now", Vec::new())]);
+
+        assert_eq!(parse("UPPER(t.name, now())"), correct_expr);
+    }
+
+    #[test]
+    fn test_placeholders() {
+        let placeholder = |index| Box::new(Expr::Placeholder(index));
+
+        let inputs = [
+            ("?", *placeholder(None)),
+            (
+                "a = $1",
+                *binary(
+                    column("a"),
+                    BinaryOperator::Equal,
+                    placeholder(Some(1)),
+                ),
+            ),
+            (
+                "? + $12",
+                *binary(
+                    placeholder(None),
+                    BinaryOperator::Add,
+                    placeholder(Some(12)),
+                ),
+            ),
+        ];
+
+        for (input, correct_expr) in inputs {
+            assert_eq!(parse(input), correct_expr, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_subqueries() {
+        let expr = parse("a NOT IN (SELECT b FROM t WHERE t.c = o.c)");
+
+        let Expr::InSubquery {
+            expr,
+            negated: true,
+            subquery,
+        } = expr
+        else {
+            panic!("expected NOT IN subquery, found {expr:?}");
+        };
+
+        assert_eq!(expr, column("a"));
+
+        // Correlated reference to outer column is kept as is.
+        let SetExpr::Select(select) = &subquery.body else {
+            panic!("expected SELECT clause, found {:?}", subquery.body);
+        };
+        let outer = Expr::Column {
+            table: Some("o".to_string()),
+            name: "c".to_string(),
+        };
+
+        assert_eq!(select.selection.as_ref().unwrap().children()[1], &outer);
+
+        let expr = parse("NOT EXISTS (SELECT 1) OR a > ALL (SELECT b FROM t)");
+
+        let Expr::BinaryOp {
+            left,
+            op: BinaryOperator::Or,
+            right,
+        } = expr
+        else {
+            panic!("expected OR, found {expr:?}");
+        };
+
+        assert!(matches!(
+            *left,
+            Expr::UnaryOp {
+                op: UnaryOperator::Not,
+                expr,
+            } if matches!(*expr, Expr::Exists(_))
+        ));
+        assert!(matches!(
+            *right,
+            Expr::Quantified {
+                op: BinaryOperator::Greater,
+                quantifier: Quantifier::All,
+                ..
+            }
+        ));
+
+        let expr = parse("(SELECT max(b) FROM t) + 1 = SOME (SELECT 2)");
+
+        let Expr::Quantified {
+            left,
+            op: BinaryOperator::Equal,
+            quantifier: Quantifier::Any,
+            ..
+        } = expr
+        else {
+            panic!("expected = SOME, found {expr:?}");
+        };
+
+        assert!(matches!(
+            *left,
+            Expr::BinaryOp { left, .. } if matches!(*left, Expr::Subquery(_))
+        ));
+
+        // Subquery used as value must return single column.
+        let inputs = [
+            "(SELECT a, b FROM t)",
+            "(SELECT * FROM t)",
+            "a IN (SELECT a, b FROM t)",
+            "a = ANY (SELECT t.* FROM t)",
+            "a < ALL ((SELECT 1, 2))",
+        ];
+
+        for input in inputs {
+            let error =
+                Parser::new(Lexer::new(input)).parse_expr().unwrap_err();
+            assert_eq!(error.kind, ErrorKind::Semantic, "{input}");
+        }
+
+        // Row set tested by EXISTS may have any width.
+        assert!(matches!(parse("EXISTS (SELECT * FROM t)"), Expr::Exists(_)));
+    }
+
+    #[test]
+    fn test_conditional_expressions() {
+        let string = |value: &str| Expr::Literal(Literal::String(value.into()));
+
+        let expr = parse(
+            "CASE WHEN a > 1 THEN 'x' WHEN a IS NULL THEN NULL ELSE 'y' END",
+        );
+        let greater = binary(column("a"), BinaryOperator::Greater, integer(1));
+        let is_null = Expr::IsNull {
+            expr: column("a"),
+            negated: false,
+        };
+        let correct_expr = Expr::Case {
+            operand: None,
+            branches: vec![
+                (*greater, string("x")),
+                (is_null, Expr::Literal(Literal::Null)),
+            ],
+            else_result: Some(Box::new(string("y"))),
+        };
+
+        assert_eq!(expr, correct_expr);
+
+        let correct_expr = Expr::Case {
+            operand: Some(column("a")),
+            branches: vec![(*integer(1), *integer(2))],
+            else_result: None,
+        };
+
+        assert_eq!(parse("CASE a WHEN 1 THEN 2 END"), correct_expr);
+
+        let cast = |expr, data_type| Expr::Cast { expr, data_type };
+
+        assert_eq!(
+            parse("CAST(a AS VARCHAR(10))"),
+            cast(column("a"), DataType::Varchar(10))
+        );
+
+        // Cast binds tighter than arithmetic operators.
+        assert_eq!(
+            parse("a::INTEGER + 1"),
+            *binary(
+                Box::new(cast(column("a"), DataType::Integer)),
+                BinaryOperator::Add,
+                integer(1)
+            )
+        );
+
+        assert_eq!(
+            parse("COALESCE(a, 0)"),
+            Expr::Coalesce(vec![*column("a"), *integer(0)])
+        );
+        assert_eq!(
+            parse("NULLIF(a, '')"),
+            Expr::NullIf {
+                left: column("a"),
+                right: Box::new(string("")),
+            }
+        );
+    }
+
+    #[test]
+    fn test_typed_literals() {
+        let typed = |data_type, value: &str| Expr::TypedLiteral {
+            data_type,
+            value: value.to_string(),
+        };
+
+        let inputs = [
+            ("DATE '2026-01-01'", typed(DataType::Date, "2026-01-01")),
+            ("TIME '10:00:00'", typed(DataType::Time, "10:00:00")),
+            (
+                "TIMESTAMP '2026-01-01 10:00'",
+                typed(DataType::Timestamp, "2026-01-01 10:00"),
+            ),
+            ("INTERVAL '3 days'", typed(DataType::Interval, "3 days")),
+            (
+                "TIMESTAMP '2026-01-01T10:00:00.5'",
+                typed(DataType::Timestamp, "2026-01-01T10:00:00.5"),
+            ),
+            // Type names are not reserved.
+            ("date", *column("date")),
+        ];
+
+        for (input, correct_expr) in inputs {
+            assert_eq!(parse(input), correct_expr, "{input}");
+        }
+
+        let inputs = [
+            "DATE 'garbage'",
+            "DATE '2026-02-30'",
+            "TIME '25:00'",
+            "TIME '10:00:00 AM'",
+            "TIMESTAMP '2026-01-01 10'",
+            "TIMESTAMP ''",
+        ];
+
+        for input in inputs {
+            let error =
+                Parser::new(Lexer::new(input)).parse_expr().unwrap_err();
+            let start = input.find('\'').unwrap();
+
+            assert_eq!(error.kind, ErrorKind::Semantic, "{input}");
+            assert_eq!(error.span.start, start, "{input}");
+            assert_eq!(error.span.end, input.len(), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_invalid_expressions() {
+        let inputs = [
+            "",
+            "a +",
+            "(a",
+            "a < b < c",
+            "a BETWEEN 1",
+            "a IS 1",
+            "a NOT NULL",
+            "f(a,)",
+            "t.",
+            "a IN (SELECT)",
+            "EXISTS a",
+            "a = ANY (1)",
+            "a + ALL (SELECT 1)",
+            "a < ALL (SELECT 1) < b",
+            "CASE END",
+            "CASE WHEN a END",
+            "CASE WHEN a THEN b",
+            "CASE a ELSE b END",
+            "CAST(a INTEGER)",
+            "CAST(a AS)",
+            "a::",
+            "a::foo",
+            "COALESCE()",
+            "NULLIF(a)",
+            "NULLIF(a, b, c)",
+        ];
+
+        for input in inputs {
+            let mut parser = Parser::new(Lexer::new(input));
+            let result = parser
+                .parse_expr()
+                .and_then(|_| parser.expect_token(&Token::End));
+
+            assert_eq!(result.unwrap_err().kind, ErrorKind::Syntax, "{input}");
+        }
+    }
+}
@@ -6,22 +6,50 @@
 //! SQL parser related declarations.
 
 pub mod ast;
+mod ddl;
+mod dml;
+mod expr;
+mod query;
+mod window;
 
 use crate::compiler::{
     lexer::{
-        Lexer,
-        token::{Keyword, Token},
+        Lexer, MAX_IDENTIFIER_LENGTH,
+        token::{Keyword, Spanned, Token},
     },
     parser::ast::Statement,
 };
-use torussql_sdk::log;
+use torussql_sdk::{
+    error::{ErrorKind, Result, Span, TorusError},
+    log,
+};
+
+/// Maximum nesting depth of expressions, subqueries & joins.
+pub const MAX_NESTING_DEPTH: usize = 64;
+
+/// SQL script parsing error struct.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScriptError {
+    /// Index of the failed statement (starting from 0).
+    pub index: usize,
+    /// Position of the failed statement up to the offending token.
+    pub span: Span,
+    /// Statement parsing error.
+    pub error: TorusError,
+}
 
 /// SQL statements parser struct.
 pub struct Parser<'a> {
     /// SQL lexer.
     lexer: Lexer<'a>,
-    /// Current token to handle.
-    current_token: Option<Token>,
+    /// Current token to handle (`None` until it is read from lexer).
+    current_token: Option<Spanned<Token>>,
+    /// Token following the current one (`None` until it is requested).
+    lookahead_token: Option<Spanned<Token>>,
+    /// Position of the last consumed token.
+    previous_span: Span,
+    /// Current nesting depth of recursively parsed constructs.
+    depth: usize,
 }
 
 impl<'a> Parser<'a> {
@@ -33,101 +61,465 @@ impl<'a> Parser<'a> {
     /// # Returns
     /// - New `Parser` object.
     pub fn new(lexer: Lexer<'a>) -> Self {
-        let mut parser = Self {
+        Self {
             lexer,
             current_token: None,
+            lookahead_token: None,
+            previous_span: Span::default(),
+            depth: 0,
+        }
+    }
+
+    /// Get current token, reading it from lexer if needed.
+    ///
+    /// # Returns
+    /// - `SQL token`  - in case of success.
+    /// - `Token::End` - in case of reaching end of SQL code.
+    /// - `Err`        - in case of failure.
+    fn current_token(&mut self) -> Result<&Token> {
+        Ok(&self.current_spanned_token()?.value)
+    }
+
+    /// Get current token with its position, reading it from lexer if needed.
+    ///
+    /// # Returns
+    /// - `SQL token`  - in case of success.
+    /// - `Token::End` - in case of reaching end of SQL code.
+    /// - `Err`        - in case of failure.
+    fn current_spanned_token(&mut self) -> Result<&Spanned<Token>> {
+        let token = match self.current_token.take() {
+            Some(token) => token,
+            None => match self.lookahead_token.take() {
+                Some(token) => token,
+                None => self.read_token()?,
+            },
+        };
+
+        Ok(self.current_token.insert(token))
+    }
+
+    /// Get token following the current one without consuming anything.
+    ///
+    /// # Returns
+    /// - `SQL token`  - in case of success.
+    /// - `Token::End` - in case of reaching end of SQL code.
+    /// - `Err`        - in case of failure.
+    fn peek_token(&mut self) -> Result<&Token> {
+        self.current_token()?;
+
+        let token = match self.lookahead_token.take() {
+            Some(token) => token,
+            None => self.read_token()?,
         };
 
-        parser.next_token();
-        parser
+        Ok(&self.lookahead_token.insert(token).value)
     }
 
-    /// Get next token.
+    /// Read token from lexer.
     ///
     /// # Returns
     /// - `SQL token`  - in case of success.
     /// - `Token::End` - in case of reaching end of SQL code.
-    /// - `None`       - in case of failure.
+    /// - `Err`        - in case of failure.
+    fn read_token(&mut self) -> Result<Spanned<Token>> {
+        let mut token = self.lexer.next_token()?;
+
+        // Skip trivia emitted by lexer in lossless mode.
+        while token.value.is_trivia() {
+            token = self.lexer.next_token()?;
+        }
+
+        Ok(token)
+    }
+
+    /// Get position of the current token.
+    ///
+    /// # Returns
+    /// - Current token span - in case of success.
+    /// - `Err`              - in case of failure.
+    fn current_span(&mut self) -> Result<Span> {
+        Ok(self.current_spanned_token()?.span)
+    }
+
+    /// Skip current token.
     #[inline(always)]
     fn next_token(&mut self) {
-        self.current_token = self.lexer.next_token();
+        if let Some(token) = self.current_token.take() {
+            self.previous_span = token.span;
+        }
     }
 
-    /// Parse SQL statement.
+    /// Consume current token if it equals to given token.
+    ///
+    /// # Parameters
+    /// - `token` - given token to consume.
+    ///
+    /// # Returns
+    /// - `true`  - if token was consumed.
+    /// - `false` - otherwise.
+    /// - `Err`   - in case of failure.
+    fn consume_token(&mut self, token: &Token) -> Result<bool> {
+        if self.current_token()? == token {
+            self.next_token();
+            return Ok(true);
+        }
+
+        Ok(false)
+    }
+
+    /// Construct error describing unexpected current token.
+    ///
+    /// # Parameters
+    /// - `expected` - given description of expected token.
+    ///
+    /// # Returns
+    /// - New syntax error.
+    fn unexpected(&mut self, expected: &str) -> TorusError {
+        match self.current_spanned_token() {
+            Ok(token) => {
+                TorusError::expected(token.span, expected, &token.value)
+            }
+            Err(error) => error,
+        }
+    }
+
+    /// Parse construct one nesting level deeper.
+    ///
+    /// # Parameters
+    /// - `parse` - given function parsing nested construct.
+    ///
+    /// # Returns
+    /// - Parsed construct - in case of success.
+    /// - `Err`            - in case of failure or too deep nesting.
+    fn parse_nested<T>(
+        &mut self,
+        parse: impl FnOnce(&mut Self) -> Result<T>,
+    ) -> Result<T> {
+        self.enter_nested()?;
+        let result = parse(self);
+        self.depth -= 1;
+
+        result
+    }
+
+    /// Go one nesting level deeper.
+    ///
+    /// # Returns
+    /// - `Ok`  - in case of success.
+    /// - `Err` - in case of too deep nesting.
+    fn enter_nested(&mut self) -> Result<()> {
+        // Bound recursion to avoid stack overflow on hostile input.
+        if self.depth >= MAX_NESTING_DEPTH {
+            let span = self.current_span()?;
+            let message = format!(
+                "statement is nested deeper than {MAX_NESTING_DEPTH} levels"
+            );
+            let kind = ErrorKind::Syntax;
+
+            return Err(TorusError::new(kind, span, message));
+        }
+
+        self.depth += 1;
+        Ok(())
+    }
+
+    /// Consume current token if it is given keyword.
+    ///
+    /// # Parameters
+    /// - `keyword` - given keyword to consume.
+    ///
+    /// # Returns
+    /// - `true`  - if keyword was consumed.
+    /// - `false` - otherwise.
+    /// - `Err`   - in case of failure.
+    fn consume_keyword(&mut self, keyword: Keyword) -> Result<bool> {
+        self.consume_token(&Token::Keyword(keyword))
+    }
+
+    /// Consume expected token.
+    ///
+    /// # Parameters
+    /// - `token` - given token to consume.
+    ///
+    /// # Returns
+    /// - `Ok`  - in case of success.
+    /// - `Err` - otherwise.
+    fn expect_token(&mut self, token: &Token) -> Result<()> {
+        if self.consume_token(token)? {
+            return Ok(());
+        }
+
+        Err(self.unexpected(&token.to_string()))
+    }
+
+    /// Consume expected keyword.
+    ///
+    /// # Parameters
+    /// - `keyword` - given keyword to consume.
+    ///
+    /// # Returns
+    /// - `Ok`  - in case of success.
+    /// - `Err` - otherwise.
+    fn expect_keyword(&mut self, keyword: Keyword) -> Result<()> {
+        if *self.current_token()? == Token::Keyword(keyword) {
+            self.next_token();
+            return Ok(());
+        }
+
+        Err(self.unexpected(&keyword.to_string()))
+    }
+
+    /// Parse SQL script consisting of statements separated by `;`.
+    /// Empty statements are skipped.
+    ///
+    /// # Returns
+    /// - `SQL statements` - in case of success.
+    /// - `Err`            - in case of failure.
+    pub fn parse_script(
+        &mut self,
+    ) -> std::result::Result<Vec<Statement>, ScriptError> {
+        let mut statements = Vec::new();
+
+        loop {
+            match self.parse_script_statement() {
+                Ok(Some(statement)) => statements.push(statement),
+                Ok(None) => return Ok(statements),
+                Err((start, error)) => {
+                    return Err(ScriptError {
+                        index: statements.len(),
+                        span: start.merge(&error.span),
+                        error,
+                    });
+                }
+            }
+        }
+    }
+
+    /// Parse next non-empty statement of SQL script with its terminator.
     ///
     /// # Returns
     /// - `SQL statement` - in case of success.
-    /// - `None`          - in case of failure.
-    pub fn parse(&mut self) -> Option<Statement> {
-        if let Some(token) = &self.current_token {
-            log::debug!("Token: {:?}", token);
-
-            return match token {
-                Token::Keyword(keyword) => match keyword {
-                    // Handle CREATE statement.
-                    Keyword::Create => self.parse_create(),
-                    _ => None,
-                },
-                _ => None,
-            };
-        };
+    /// - `None`          - in case of reaching end of script.
+    /// - `Err`           - failed statement start position & error.
+    fn parse_script_statement(
+        &mut self,
+    ) -> std::result::Result<Option<Statement>, (Span, TorusError)> {
+        // Lexer errors before statement start point at themselves.
+        let at_error = |error: TorusError| (error.span, error);
+
+        // Skip empty statements.
+        while self.consume_token(&Token::Semicolon).map_err(at_error)? {}
+
+        let token = self.current_spanned_token().map_err(at_error)?;
+        let start = token.span;
+
+        if token.value == Token::End {
+            return Ok(None);
+        }
+
+        let result = self.parse().and_then(|statement| {
+            self.consume_token(&Token::Semicolon)?;
+            Ok(Some(statement))
+        });
 
-        None
+        result.map_err(|error| (start, error))
     }
 
-    /// Parse create statement.
+    /// Parse SQL statement ending with `;` or end of SQL code.
+    /// Terminator is not consumed.
     ///
     /// # Returns
     /// - `SQL statement` - in case of success.
-    /// - `None`          - in case of failure.
-    fn parse_create(&mut self) -> Option<Statement> {
-        // Handle next token.
-        self.next_token();
+    /// - `Err`           - in case of failure.
+    pub fn parse(&mut self) -> Result<Statement> {
+        let statement = self.parse_statement()?;
 
-        if let Some(Token::Keyword(keyword)) = &self.current_token {
-            return match keyword {
-                // Handle CREATE DATABASE statement.
-                Keyword::Database => self.parse_create_database(),
-                _ => return None,
-            };
+        // Forbid trailing tokens, e.g. misspelled clauses.
+        match self.current_token()? {
+            Token::Semicolon | Token::End => Ok(statement),
+            _ => Err(self.unexpected("';'")),
         }
+    }
 
-        None
+    /// Parse SQL statement ending with `;` or end of SQL code
+    /// together with its position. Terminator is not consumed.
+    ///
+    /// # Returns
+    /// - `SQL statement` - in case of success.
+    /// - `Err`           - in case of failure.
+    pub fn parse_spanned(&mut self) -> Result<Spanned<Statement>> {
+        let start = self.current_span()?;
+        let statement = self.parse()?;
+
+        Ok(Spanned::new(statement, start.merge(&self.previous_span)))
     }
 
-    /// Parse create database statement.
+    /// Parse SQL statement.
     ///
     /// # Returns
     /// - `SQL statement` - in case of success.
-    /// - `None`          - in case of failure.
-    fn parse_create_database(&mut self) -> Option<Statement> {
-        // Get database name.
-        self.next_token();
+    /// - `Err`           - in case of failure.
+    fn parse_statement(&mut self) -> Result<Statement> {
+        let token = self.current_token()?;
+        log::debug!("Token: {:?}", token);
 
-        if let Some(Token::String(name)) = &self.current_token {
-            return Some(Statement::CreateDatabase {
-                name: name.to_string(),
-            });
+        match token {
+            // Handle CREATE statement.
+            Token::Keyword(Keyword::Create) => self.parse_create(),
+            // Handle DROP statement.
+            Token::Keyword(Keyword::Drop) => self.parse_drop(),
+            // Handle ALTER statement.
+            Token::Keyword(Keyword::Alter) => self.parse_alter(),
+            // Handle SELECT statement.
+            Token::Keyword(Keyword::Select | Keyword::With)
+            | Token::LeftParen => {
+                Ok(Statement::Query(Box::new(self.parse_query()?)))
+            }
+            // Handle INSERT statement.
+            Token::Keyword(Keyword::Insert) => self.parse_insert(),
+            // Handle UPDATE statement.
+            Token::Keyword(Keyword::Update) => self.parse_update(),
+            // Handle DELETE statement.
+            Token::Keyword(Keyword::Delete) => self.parse_delete(),
+            // Handle TRUNCATE statement.
+            Token::Keyword(Keyword::Truncate) => self.parse_truncate(),
+            // Handle USE statement.
+            Token::Keyword(Keyword::Use) => self.parse_use(),
+            _ => Err(self.unexpected("statement")),
+        }
+    }
+
+    /// Parse list of comma-separated items.
+    ///
+    /// # Parameters
+    /// - `parse_item` - given function to parse single item.
+    ///
+    /// # Returns
+    /// - Parsed items - in case of success.
+    /// - `Err`        - in case of failure.
+    fn parse_comma_separated<T>(
+        &mut self,
+        mut parse_item: impl FnMut(&mut Self) -> Result<T>,
+    ) -> Result<Vec<T>> {
+        let mut items = vec![parse_item(self)?];
+
+        while self.consume_token(&Token::Comma)? {
+            items.push(parse_item(self)?);
+        }
+
+        Ok(items)
+    }
+
+    /// Parse list of comma-separated items enclosed in parentheses.
+    ///
+    /// # Parameters
+    /// - `parse_item` - given function to parse single item.
+    ///
+    /// # Returns
+    /// - Parsed items - in case of success.
+    /// - `Err`        - in case of failure.
+    fn parse_parenthesized<T>(
+        &mut self,
+        parse_item: impl FnMut(&mut Self) -> Result<T>,
+    ) -> Result<Vec<T>> {
+        self.expect_token(&Token::LeftParen)?;
+        let items = self.parse_comma_separated(parse_item)?;
+        self.expect_token(&Token::RightParen)?;
+
+        Ok(items)
+    }
+
+    /// Parse non-negative integer literal.
+    ///
+    /// # Parameters
+    /// - `expected` - given description of expected integer.
+    ///
+    /// # Returns
+    /// - Integer with its position - in case of success.
+    /// - `Err`                     - in case of failure.
+    fn parse_unsigned_integer(
+        &mut self,
+        expected: &str,
+    ) -> Result<Spanned<u64>> {
+        let token = self.current_spanned_token()?;
+
+        if let Token::Integer(value) = token.value {
+            let span = token.span;
+            self.next_token();
+
+            return Ok(Spanned::new(value, span));
         }
 
-        None
+        Err(self.unexpected(expected))
+    }
+
+    /// Parse identifier.
+    ///
+    /// # Parameters
+    /// - `expected` - given description of expected identifier.
+    ///
+    /// # Returns
+    /// - `Identifier` - in case of success.
+    /// - `Err`        - in case of failure.
+    fn parse_identifier(&mut self, expected: &str) -> Result<String> {
+        let legacy = self.lexer.options().double_quoted_strings;
+
+        let name = match self.current_token()? {
+            Token::Identifier(name) | Token::QuotedIdentifier(name) => {
+                name.to_string()
+            }
+            // Non-reserved keywords can be used as names.
+            Token::Keyword(keyword) if !keyword.is_reserved() => {
+                keyword.as_str().to_lowercase()
+            }
+            // Double quoted names are lexed as strings in compatibility mode.
+            Token::String(name) if legacy => {
+                if name.is_empty() || name.len() > MAX_IDENTIFIER_LENGTH {
+                    return Err(self.unexpected(expected));
+                }
+
+                name.to_string()
+            }
+            _ => return Err(self.unexpected(expected)),
+        };
+
+        self.next_token();
+        Ok(name)
+    }
+}
+
+/// Check whether token can be used as name of database object.
+///
+/// # Parameters
+/// - `token` - given SQL token to check.
+///
+/// # Returns
+/// - `true`  - if token is identifier or non-reserved keyword.
+/// - `false` - otherwise.
+fn is_name(token: &Token) -> bool {
+    match token {
+        Token::Identifier(_) | Token::QuotedIdentifier(_) => true,
+        Token::Keyword(keyword) => !keyword.is_reserved(),
+        _ => false,
     }
 }
 
 #[cfg(test)]
 pub mod tests {
     use crate::compiler::{
-        lexer::Lexer,
-        parser::{Parser, ast::Statement},
+        lexer::{Lexer, LexerOptions},
+        parser::{MAX_NESTING_DEPTH, Parser, ScriptError, ast::Statement},
+    };
+    use torussql_sdk::{
+        error::{ErrorKind, Span},
+        log,
     };
-    use torussql_sdk::log;
 
-    fn create_parser(input: &str) -> Parser {
+    fn create_parser(input: &str) -> Parser<'_> {
         let lexer = Lexer::new(input);
         Parser::new(lexer)
     }
 
-    // TODO: add TorusSQL errors.
     // TODO: add more tests for CREATE DATABASE statement.
     #[test]
     fn test_create_database() {
@@ -136,9 +528,165 @@ pub mod tests {
 
         let correct_statement = Statement::CreateDatabase {
             name: "MyDB".to_string(),
+            if_not_exists: false,
         };
 
         log::debug!("Statement: {:?}", statement);
         assert_eq!(statement, correct_statement);
     }
+
+    #[test]
+    fn test_create_database_identifiers() {
+        let inputs = [
+            ("CREATE DATABASE MyDB;", "mydb"),
+            ("CREATE DATABASE \"My DB\";", "My DB"),
+            ("CREATE DATABASE Name;", "name"),
+        ];
+
+        for (input, name) in inputs {
+            let statement = create_parser(input).parse().unwrap();
+            let name = name.to_string();
+            let if_not_exists = false;
+
+            assert_eq!(
+                statement,
+                Statement::CreateDatabase {
+                    name,
+                    if_not_exists
+                }
+            );
+        }
+
+        for input in ["CREATE DATABASE 'MyDB';", "CREATE DATABASE TABLE;"] {
+            let error = create_parser(input).parse().unwrap_err();
+            assert_eq!(error.kind, ErrorKind::Syntax);
+        }
+    }
+
+    #[test]
+    fn test_create_database_double_quoted_strings() {
+        let options = LexerOptions {
+            double_quoted_strings: true,
+            preserve_trivia: true,
+        };
+        let input = "CREATE /* db */ DATABASE \"MyDB\";";
+        let lexer = Lexer::with_options(input, options);
+        let statement = Parser::new(lexer).parse().unwrap();
+
+        let name = "MyDB".to_string();
+        let if_not_exists = false;
+        assert_eq!(
+            statement,
+            Statement::CreateDatabase {
+                name,
+                if_not_exists
+            }
+        );
+    }
+
+    #[test]
+    fn test_create_database_missing_name() {
+        let mut parser = create_parser("CREATE DATABASE ;");
+        let error = parser.parse().unwrap_err();
+
+        assert_eq!(error.kind, ErrorKind::Syntax);
+        assert_eq!(error.span, Span::new(16, 17, 1, 17));
+        assert_eq!(error.message, "expected database name, found ';'");
+    }
+
+    #[test]
+    fn test_unknown_statement() {
+        let mut parser = create_parser(";");
+        let error = parser.parse().unwrap_err();
+
+        assert_eq!(error.kind, ErrorKind::Syntax);
+        assert_eq!(error.message, "expected statement, found ';'");
+    }
+
+    #[test]
+    fn test_nesting_depth() {
+        let nest = |depth: usize, open: &str, inner: &str, close: &str| {
+            format!("{}{inner}{}", open.repeat(depth), close.repeat(depth))
+        };
+
+        let depth = MAX_NESTING_DEPTH / 2;
+        let inputs = [
+            format!("SELECT {}", nest(depth, "(", "1", ")")),
+            format!("SELECT * FROM {}", nest(depth, "(", "a", ")")),
+        ];
+
+        for input in inputs {
+            assert!(create_parser(&input).parse().is_ok(), "{input}");
+        }
+
+        let depth = 1000;
+        let inputs = [
+            format!("SELECT {}", nest(depth, "(", "1", ")")),
+            format!("SELECT {}1", "NOT ".repeat(depth)),
+            format!("SELECT {}", nest(depth, "(SELECT ", "1", ")")),
+            format!("SELECT * FROM {}", nest(depth, "(", "a", ")")),
+            format!("SELECT {}", nest(depth, "CAST(", "1", " AS INT)")),
+        ];
+
+        for input in inputs {
+            let error = create_parser(&input).parse().unwrap_err();
+
+            assert_eq!(error.kind, ErrorKind::Syntax, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_operator_chain_depth() {
+        let chain =
+            |length: usize| format!("SELECT {}", vec!["1"; length].join(" + "));
+
+        let input = chain(MAX_NESTING_DEPTH / 2);
+        assert!(create_parser(&input).parse().is_ok());
+
+        // Left-deep chain of operators nests as deep as its length.
+        let input = chain(5000);
+        let error = create_parser(&input).parse().unwrap_err();
+
+        assert_eq!(error.kind, ErrorKind::Syntax);
+    }
+
+    #[test]
+    fn test_parse_script() {
+        let input = ";; CREATE DATABASE a;\n\n;CREATE DATABASE b ; ;\
+                     CREATE DATABASE c";
+        let statements = create_parser(input).parse_script().unwrap();
+
+        let names = ["a", "b", "c"];
+        let correct_statements: Vec<_> = names
+            .iter()
+            .map(|name| Statement::CreateDatabase {
+                name: name.to_string(),
+                if_not_exists: false,
+            })
+            .collect();
+
+        assert_eq!(statements, correct_statements);
+
+        for input in ["", " ;; -- comment"] {
+            let statements = create_parser(input).parse_script().unwrap();
+            assert!(statements.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_parse_script_error() {
+        let input = "CREATE DATABASE a;\nCREATE DATABASE b c;";
+        let error = create_parser(input).parse_script().unwrap_err();
+
+        let ScriptError { index, span, error } = error;
+        assert_eq!(index, 1);
+        assert_eq!(span, Span::new(19, 38, 2, 1));
+        assert_eq!(error.message, "expected ';', found identifier c");
+        assert_eq!(error.span, Span::new(37, 38, 2, 19));
+
+        let input = "CREATE DATABASE a; CREATE @";
+        let error = create_parser(input).parse_script().unwrap_err();
+        assert_eq!(error.index, 1);
+        assert_eq!(error.error.kind, ErrorKind::Lexical);
+    }
 }
@@ -0,0 +1,1272 @@
+// Project name: TorusSQL.
+// Description: Relational database management system.
+// Licence: GPL-3.0.
+// Author: Alexander (@alkuzin).
+
+//! SQL queries parsing declarations.
+
+use crate::compiler::{
+    lexer::token::{Keyword, Token},
+    parser::{
+        Parser,
+        ast::{
+            Cte, Expr, JoinConstraint, JoinKind, Literal, OrderByExpr, Query,
+            Select, SelectItem, SetExpr, SetOperator, TableRef, With,
+        },
+        is_name,
+        window::{check_order_window_refs, check_window_refs},
+    },
+};
+use torussql_sdk::error::{ErrorKind, Result, Span, TorusError};
+
+/// Set operators binding power enumeration (from the loosest to the tightest).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum SetPrecedence {
+    /// Not a set operator.
+    Lowest,
+    /// `UNION`, `EXCEPT`.
+    Union,
+    /// `INTERSECT`.
+    Intersect,
+}
+
+impl Parser<'_> {
+    /// Parse SELECT query.
+    ///
+    /// # Returns
+    /// - `SQL query` - in case of success.
+    /// - `Err`       - in case of failure.
+    pub(super) fn parse_query(&mut self) -> Result<Query> {
+        self.parse_nested(Self::parse_query_body)
+    }
+
+    /// Parse SELECT query at current nesting level.
+    ///
+    /// # Returns
+    /// - `SQL query` - in case of success.
+    /// - `Err`       - in case of failure.
+    fn parse_query_body(&mut self) -> Result<Query> {
+        let with = match *self.current_token()? {
+            Token::Keyword(Keyword::With) => Some(self.parse_with()?),
+            _ => None,
+        };
+
+        let body = self.parse_set_expr(SetPrecedence::Lowest)?;
+
+        let span = self.current_span()?;
+        let order_by = self.parse_order_by()?;
+        let span = span.merge(&self.previous_span);
+
+        let limit = match self.consume_keyword(Keyword::Limit)? {
+            true => Some(self.parse_unsigned_integer("row count")?.value),
+            false => None,
+        };
+
+        let offset = match self.consume_keyword(Keyword::Offset)? {
+            true => Some(self.parse_unsigned_integer("row count")?.value),
+            false => None,
+        };
+
+        let query = Query {
+            with,
+            body,
+            order_by,
+            limit,
+            offset,
+        };
+
+        check_order_window_refs(&query, span)?;
+        Ok(query)
+    }
+
+    /// Parse `WITH [RECURSIVE] name [(columns)] AS (query), ...` clause.
+    ///
+    /// # Returns
+    /// - `WITH clause` - in case of success.
+    /// - `Err`         - in case of failure.
+    fn parse_with(&mut self) -> Result<With> {
+        self.expect_keyword(Keyword::With)?;
+
+        let recursive = self.consume_keyword(Keyword::Recursive)?;
+        let mut ctes: Vec<Cte> = Vec::new();
+
+        loop {
+            let span = self.current_span()?;
+            let cte = self.parse_cte()?;
+
+            if ctes.iter().any(|other| other.name == cte.name) {
+                let message = format!(
+                    "WITH query name \"{}\" specified more than once",
+                    cte.name
+                );
+                let kind = ErrorKind::Semantic;
+
+                return Err(TorusError::new(kind, span, message));
+            }
+
+            ctes.push(cte);
+
+            if !self.consume_token(&Token::Comma)? {
+                break;
+            }
+        }
+
+        Ok(With { recursive, ctes })
+    }
+
+    /// Parse common table expression.
+    ///
+    /// # Returns
+    /// - `Common table expression` - in case of success.
+    /// - `Err`                     - in case of failure.
+    fn parse_cte(&mut self) -> Result<Cte> {
+        let span = self.current_span()?;
+        let name = self.parse_identifier("WITH query name")?;
+
+        let columns = match self.current_token()? {
+            Token::LeftParen => self.parse_column_list()?,
+            _ => Vec::new(),
+        };
+
+        self.expect_keyword(Keyword::As)?;
+        let query = self.parse_subquery()?;
+
+        if let Some(count) = column_count(&query.body)
+            && !columns.is_empty()
+            && columns.len() != count
+        {
+            let message = format!(
+                "WITH query \"{name}\" has {count} columns, {} specified",
+                columns.len()
+            );
+            let kind = ErrorKind::Semantic;
+
+            return Err(TorusError::new(kind, span, message));
+        }
+
+        Ok(Cte {
+            name,
+            columns,
+            query,
+        })
+    }
+
+    /// Parse query body with set operators binding tighter than given one.
+    ///
+    /// # Parameters
+    /// - `precedence` - given binding power of enclosing set operator.
+    ///
+    /// # Returns
+    /// - `Query body` - in case of success.
+    /// - `Err`        - in case of failure.
+    fn parse_set_expr(&mut self, precedence: SetPrecedence) -> Result<SetExpr> {
+        let mut left = self.parse_set_operand()?;
+
+        loop {
+            let (op, next_precedence) = match self.current_token()? {
+                Token::Keyword(Keyword::Union) => {
+                    (SetOperator::Union, SetPrecedence::Union)
+                }
+                Token::Keyword(Keyword::Except) => {
+                    (SetOperator::Except, SetPrecedence::Union)
+                }
+                Token::Keyword(Keyword::Intersect) => {
+                    (SetOperator::Intersect, SetPrecedence::Intersect)
+                }
+                _ => return Ok(left),
+            };
+
+            // Set operators are left-associative.
+            if next_precedence <= precedence {
+                return Ok(left);
+            }
+
+            let span = self.current_span()?;
+            self.next_token();
+
+            let all = match self.current_token()? {
+                Token::Keyword(Keyword::All) => {
+                    self.next_token();
+                    true
+                }
+                Token::Keyword(Keyword::Distinct) => {
+                    self.next_token();
+                    false
+                }
+                _ => false,
+            };
+
+            let right = self.parse_set_expr(next_precedence)?;
+
+            if let (Some(left), Some(right)) =
+                (column_count(&left), column_count(&right))
+                && left != right
+            {
+                let name = match op {
+                    SetOperator::Union => "UNION",
+                    SetOperator::Intersect => "INTERSECT",
+                    SetOperator::Except => "EXCEPT",
+                };
+                let message = format!(
+                    "each {name} query must have the same number of columns"
+                );
+                let kind = ErrorKind::Semantic;
+
+                return Err(TorusError::new(kind, span, message));
+            }
+
+            left = SetExpr::SetOperation {
+                op,
+                all,
+                left: Box::new(left),
+                right: Box::new(right),
+            };
+        }
+    }
+
+    /// Parse SELECT clause or parenthesized query.
+    ///
+    /// # Returns
+    /// - `Query body` - in case of success.
+    /// - `Err`        - in case of failure.
+    fn parse_set_operand(&mut self) -> Result<SetExpr> {
+        if self.consume_token(&Token::LeftParen)? {
+            let query = self.parse_query()?;
+            self.expect_token(&Token::RightParen)?;
+
+            return Ok(SetExpr::Query(Box::new(query)));
+        }
+
+        Ok(SetExpr::Select(Box::new(self.parse_select()?)))
+    }
+
+    /// Parse SELECT clause.
+    ///
+    /// # Returns
+    /// - `SELECT clause` - in case of success.
+    /// - `Err`           - in case of failure.
+    fn parse_select(&mut self) -> Result<Select> {
+        self.expect_keyword(Keyword::Select)?;
+
+        let distinct = match self.current_token()? {
+            Token::Keyword(Keyword::Distinct) => {
+                self.next_token();
+                true
+            }
+            Token::Keyword(Keyword::All) => {
+                self.next_token();
+                false
+            }
+            _ => false,
+        };
+
+        // Remember positions of items for grouping errors.
+        let mut spans = Vec::new();
+
+        let projection = self.parse_comma_separated(|parser| {
+            spans.push(parser.current_span()?);
+            parser.parse_select_item()
+        })?;
+
+        let from = match self.consume_keyword(Keyword::From)? {
+            true => self.parse_comma_separated(Self::parse_table_ref)?,
+            false => Vec::new(),
+        };
+
+        let selection = self.parse_where()?;
+        let group_by = self.parse_group_by(&projection)?;
+
+        let span = self.current_span()?;
+        let having = match self.consume_keyword(Keyword::Having)? {
+            true => Some(self.parse_expr()?),
+            false => None,
+        };
+
+        if having.as_ref().is_some_and(Expr::contains_window) {
+            let message = "window functions are not allowed in HAVING";
+            return Err(TorusError::new(ErrorKind::Semantic, span, message));
+        }
+
+        let windows = self.parse_window_clause()?;
+
+        let select = Select {
+            distinct,
+            projection,
+            from,
+            selection,
+            group_by,
+            having,
+            windows,
+        };
+
+        check_grouping(&select, &spans, span)?;
+        check_window_refs(&select, &spans)?;
+        Ok(select)
+    }
+
+    /// Parse optional `GROUP BY expr, ...` clause.
+    ///
+    /// # Parameters
+    /// - `projection` - given values returned by SELECT query.
+    ///
+    /// # Returns
+    /// - Grouping expressions - in case of success.
+    /// - `Err`                - in case of failure.
+    fn parse_group_by(
+        &mut self,
+        projection: &[SelectItem],
+    ) -> Result<Vec<Expr>> {
+        if !self.consume_keyword(Keyword::Group)? {
+            return Ok(Vec::new());
+        }
+
+        self.expect_keyword(Keyword::By)?;
+
+        self.parse_comma_separated(|parser| {
+            let span = parser.current_span()?;
+            let expr = parser.parse_row_expr("GROUP BY")?;
+
+            if let Expr::Literal(Literal::Integer(position)) = expr {
+                let message = match resolve_ordinal(projection, position) {
+                    None => format!(
+                        "GROUP BY position {position} is not in select list"
+                    ),
+                    // Referenced item is grouped as if written in place.
+                    Some(item) if item.contains_aggregate() => {
+                        "aggregate functions are not allowed in GROUP BY"
+                            .to_string()
+                    }
+                    Some(item) if item.contains_window() => {
+                        "window functions are not allowed in GROUP BY"
+                            .to_string()
+                    }
+                    Some(_) => return Ok(expr),
+                };
+                let kind = ErrorKind::Semantic;
+
+                return Err(TorusError::new(kind, span, message));
+            }
+
+            Ok(expr)
+        })
+    }
+
+    /// Parse value returned by SELECT query.
+    ///
+    /// # Returns
+    /// - `SELECT item` - in case of success.
+    /// - `Err`         - in case of failure.
+    fn parse_select_item(&mut self) -> Result<SelectItem> {
+        if self.consume_token(&Token::Asterisk)? {
+            return Ok(SelectItem::Wildcard);
+        }
+
+        // Qualified wildcard looks like a start of qualified column reference.
+        let expr = if is_name(self.current_token()?)
+            && *self.peek_token()? == Token::Dot
+        {
+            let table = self.parse_identifier("table name")?;
+            self.expect_token(&Token::Dot)?;
+
+            if self.consume_token(&Token::Asterisk)? {
+                return Ok(SelectItem::QualifiedWildcard(table));
+            }
+
+            let name = self.parse_identifier("column name")?;
+            let table = Some(table);
+
+            self.parse_expr_from(Expr::Column { table, name })?
+        } else {
+            self.parse_expr()?
+        };
+
+        let alias = self.parse_alias()?;
+        Ok(SelectItem::Expr { expr, alias })
+    }
+
+    /// Parse source of rows with joins.
+    ///
+    /// # Returns
+    /// - `Table reference` - in case of success.
+    /// - `Err`             - in case of failure.
+    fn parse_table_ref(&mut self) -> Result<TableRef> {
+        let mut table = self.parse_table_factor()?;
+
+        // Joins are left-associative.
+        while let Some((kind, natural)) = self.parse_join_kind()? {
+            let right = self.parse_table_factor()?;
+
+            let constraint = match (kind, natural) {
+                (JoinKind::Cross, _) => JoinConstraint::None,
+                (_, true) => JoinConstraint::Natural,
+                _ => self.parse_join_constraint()?,
+            };
+
+            table = TableRef::Join {
+                left: Box::new(table),
+                right: Box::new(right),
+                kind,
+                constraint,
+            };
+        }
+
+        Ok(table)
+    }
+
+    /// Parse optional `[NATURAL] [INNER|LEFT|RIGHT|FULL [OUTER]|CROSS] JOIN`.
+    ///
+    /// # Returns
+    /// - Join type with NATURAL flag - if clause is present.
+    /// - `None`                      - otherwise.
+    /// - `Err`                       - in case of failure.
+    fn parse_join_kind(&mut self) -> Result<Option<(JoinKind, bool)>> {
+        let natural = self.consume_keyword(Keyword::Natural)?;
+
+        let kind = match self.current_token()? {
+            Token::Keyword(Keyword::Join) => JoinKind::Inner,
+            Token::Keyword(Keyword::Inner) => {
+                self.next_token();
+                JoinKind::Inner
+            }
+            Token::Keyword(Keyword::Cross) if !natural => {
+                self.next_token();
+                JoinKind::Cross
+            }
+            Token::Keyword(
+                keyword @ (Keyword::Left | Keyword::Right | Keyword::Full),
+            ) => {
+                let kind = match keyword {
+                    Keyword::Left => JoinKind::Left,
+                    Keyword::Right => JoinKind::Right,
+                    _ => JoinKind::Full,
+                };
+
+                self.next_token();
+                self.consume_keyword(Keyword::Outer)?;
+                kind
+            }
+            _ if natural => return Err(self.unexpected("JOIN")),
+            _ => return Ok(None),
+        };
+
+        self.expect_keyword(Keyword::Join)?;
+        Ok(Some((kind, natural)))
+    }
+
+    /// Parse `ON condition` or `USING (columns)` join clause.
+    ///
+    /// # Returns
+    /// - `Join constraint` - in case of success.
+    /// - `Err`             - in case of failure.
+    fn parse_join_constraint(&mut self) -> Result<JoinConstraint> {
+        match self.current_token()? {
+            Token::Keyword(Keyword::On) => {
+                self.next_token();
+                Ok(JoinConstraint::On(self.parse_expr()?))
+            }
+            Token::Keyword(Keyword::Using) => {
+                self.next_token();
+                Ok(JoinConstraint::Using(self.parse_column_list()?))
+            }
+            _ => Err(self.unexpected("ON or USING")),
+        }
+    }
+
+    /// Parse table, subquery or parenthesized joins.
+    ///
+    /// # Returns
+    /// - `Table reference` - in case of success.
+    /// - `Err`             - in case of failure.
+    fn parse_table_factor(&mut self) -> Result<TableRef> {
+        if *self.current_token()? != Token::LeftParen {
+            let name = self.parse_identifier("table name")?;
+            let alias = self.parse_alias()?;
+
+            return Ok(TableRef::Table { name, alias });
+        }
+
+        // Handle derived table.
+        if self.is_subquery_start()? {
+            let query = self.parse_subquery()?;
+
+            let Some(alias) = self.parse_alias()? else {
+                return Err(self.unexpected("subquery alias"));
+            };
+
+            return Ok(TableRef::Derived { query, alias });
+        }
+
+        self.expect_token(&Token::LeftParen)?;
+        let table = self.parse_nested(Self::parse_table_ref)?;
+        self.expect_token(&Token::RightParen)?;
+
+        Ok(table)
+    }
+
+    /// Check whether current token starts parenthesized subquery.
+    ///
+    /// # Returns
+    /// - `true`  - if current tokens are `(` & `SELECT` or `WITH`.
+    /// - `false` - otherwise.
+    /// - `Err`   - in case of failure.
+    pub(super) fn is_subquery_start(&mut self) -> Result<bool> {
+        Ok(*self.current_token()? == Token::LeftParen
+            && matches!(
+                self.peek_token()?,
+                Token::Keyword(Keyword::Select | Keyword::With)
+            ))
+    }
+
+    /// Parse parenthesized subquery.
+    ///
+    /// # Returns
+    /// - `SQL query` - in case of success.
+    /// - `Err`       - in case of failure.
+    pub(super) fn parse_subquery(&mut self) -> Result<Box<Query>> {
+        self.expect_token(&Token::LeftParen)?;
+        let query = self.parse_query()?;
+        self.expect_token(&Token::RightParen)?;
+
+        Ok(Box::new(query))
+    }
+
+    /// Parse parenthesized subquery returning single column.
+    ///
+    /// # Returns
+    /// - `SQL query` - in case of success.
+    /// - `Err`       - in case of failure.
+    pub(super) fn parse_column_subquery(&mut self) -> Result<Box<Query>> {
+        let span = self.current_span()?;
+        let query = self.parse_subquery()?;
+
+        let message = match column_count(&query.body) {
+            Some(1) => return Ok(query),
+            Some(_) => "subquery must return only one column",
+            // Width of wildcard is known only from catalog.
+            None => "subquery must list its column explicitly",
+        };
+        let kind = ErrorKind::Semantic;
+
+        Err(TorusError::new(kind, span, message))
+    }
+
+    /// Parse optional `[AS] alias` clause.
+    ///
+    /// # Returns
+    /// - `Alias` - if clause is present.
+    /// - `None`  - otherwise.
+    /// - `Err`   - in case of failure.
+    fn parse_alias(&mut self) -> Result<Option<String>> {
+        if self.consume_keyword(Keyword::As)? {
+            return Ok(Some(self.parse_identifier("alias")?));
+        }
+
+        // Keywords can't be used as alias without AS.
+        match self.current_token()? {
+            Token::Identifier(_) | Token::QuotedIdentifier(_) => {
+                Ok(Some(self.parse_identifier("alias")?))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Parse optional `ORDER BY expr [ASC|DESC] [NULLS FIRST|LAST], ...`.
+    ///
+    /// # Returns
+    /// - Sorting keys - in case of success.
+    /// - `Err`        - in case of failure.
+    pub(super) fn parse_order_by(&mut self) -> Result<Vec<OrderByExpr>> {
+        if !self.consume_keyword(Keyword::Order)? {
+            return Ok(Vec::new());
+        }
+
+        self.expect_keyword(Keyword::By)?;
+        self.parse_comma_separated(Self::parse_order_by_expr)
+    }
+
+    /// Parse ORDER BY clause item.
+    ///
+    /// # Returns
+    /// - `ORDER BY item` - in case of success.
+    /// - `Err`           - in case of failure.
+    fn parse_order_by_expr(&mut self) -> Result<OrderByExpr> {
+        let expr = self.parse_expr()?;
+
+        let descending = match self.current_token()? {
+            Token::Keyword(Keyword::Asc) => false,
+            Token::Keyword(Keyword::Desc) => true,
+            _ => return self.parse_nulls_order(expr, false),
+        };
+
+        self.next_token();
+        self.parse_nulls_order(expr, descending)
+    }
+
+    /// Parse optional `NULLS FIRST|LAST` clause of ORDER BY item.
+    ///
+    /// # Parameters
+    /// - `expr`       - given sorting key.
+    /// - `descending` - given flag to sort in descending order.
+    ///
+    /// # Returns
+    /// - `ORDER BY item` - in case of success.
+    /// - `Err`           - in case of failure.
+    fn parse_nulls_order(
+        &mut self,
+        expr: Expr,
+        descending: bool,
+    ) -> Result<OrderByExpr> {
+        // NULL values are larger than others by default.
+        let mut nulls_first = descending;
+
+        if self.consume_keyword(Keyword::Nulls)? {
+            nulls_first = match self.current_token()? {
+                Token::Keyword(Keyword::First) => true,
+                Token::Keyword(Keyword::Last) => false,
+                _ => return Err(self.unexpected("FIRST or LAST")),
+            };
+
+            self.next_token();
+        }
+
+        Ok(OrderByExpr {
+            expr,
+            descending,
+            nulls_first,
+        })
+    }
+
+    /// Parse optional `WHERE condition` clause.
+    ///
+    /// # Returns
+    /// - `Condition` - if clause is present.
+    /// - `None`      - otherwise.
+    /// - `Err`       - in case of failure.
+    pub(super) fn parse_where(&mut self) -> Result<Option<Expr>> {
+        if !self.consume_keyword(Keyword::Where)? {
+            return Ok(None);
+        }
+
+        Ok(Some(self.parse_row_expr("WHERE")?))
+    }
+
+    /// Parse SQL expression evaluated for each row separately.
+    ///
+    /// # Parameters
+    /// - `clause` - given name of clause containing expression.
+    ///
+    /// # Returns
+    /// - `SQL expression` - in case of success.
+    /// - `Err`            - in case of failure or aggregate/window function.
+    pub(super) fn parse_row_expr(&mut self, clause: &str) -> Result<Expr> {
+        let span = self.current_span()?;
+        let expr = self.parse_expr()?;
+
+        let function = if expr.contains_aggregate() {
+            "aggregate"
+        } else if expr.contains_window() {
+            "window"
+        } else {
+            return Ok(expr);
+        };
+
+        let message =
+            format!("{function} functions are not allowed in {clause}");
+        Err(TorusError::new(ErrorKind::Semantic, span, message))
+    }
+}
+
+/// Get number of columns returned by query body.
+///
+/// # Parameters
+/// - `body` - given query body.
+///
+/// # Returns
+/// - Number of columns - in case of success.
+/// - `None`            - if it depends on tables columns (wildcards).
+pub(super) fn column_count(body: &SetExpr) -> Option<usize> {
+    match body {
+        SetExpr::Select(select) => select
+            .projection
+            .iter()
+            .all(|item| matches!(item, SelectItem::Expr { .. }))
+            .then_some(select.projection.len()),
+        SetExpr::Query(query) => column_count(&query.body),
+        SetExpr::SetOperation { left, .. } => column_count(left),
+    }
+}
+
+/// Get expression of SELECT item referred by its position.
+///
+/// # Parameters
+/// - `projection` - given values returned by SELECT query.
+/// - `position`   - given position of SELECT item (starting from 1).
+///
+/// # Returns
+/// - `SELECT item expression` - in case of success.
+/// - `None`                   - if there is no such expression.
+fn resolve_ordinal(projection: &[SelectItem], position: i64) -> Option<&Expr> {
+    let index = usize::try_from(position).ok()?.checked_sub(1)?;
+
+    match projection.get(index)? {
+        SelectItem::Expr { expr, .. } => Some(expr),
+        _ => None,
+    }
+}
+
+/// Check that grouped SELECT query returns only grouped columns.
+///
+/// # Parameters
+/// - `select`      - given SELECT clause.
+/// - `spans`       - given positions of SELECT items.
+/// - `having_span` - given position of HAVING clause.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - otherwise.
+fn check_grouping(
+    select: &Select,
+    spans: &[Span],
+    having_span: Span,
+) -> Result<()> {
+    let aggregated = select.projection.iter().any(|item| match item {
+        SelectItem::Expr { expr, .. } => expr.contains_aggregate(),
+        _ => false,
+    });
+
+    if select.group_by.is_empty() && select.having.is_none() && !aggregated {
+        return Ok(());
+    }
+
+    // Ordinals are replaced with expressions they refer to.
+    let keys: Vec<_> = select
+        .group_by
+        .iter()
+        .map(|key| match key {
+            Expr::Literal(Literal::Integer(position)) => {
+                resolve_ordinal(&select.projection, *position).unwrap_or(key)
+            }
+            _ => key,
+        })
+        .collect();
+
+    let items = select.projection.iter().zip(spans.iter().copied());
+    let having = select.having.iter().map(|having| (having, having_span));
+
+    let exprs = items
+        .map(|(item, span)| match item {
+            SelectItem::Expr { expr, .. } => Ok((expr, span)),
+            _ => {
+                let message = "wildcard is not allowed in grouped query";
+                Err(TorusError::new(ErrorKind::Semantic, span, message))
+            }
+        })
+        .chain(having.map(Ok));
+
+    for expr in exprs {
+        let (expr, span) = expr?;
+
+        if let Some(Expr::Column { table, name }) =
+            find_ungrouped_column(expr, &keys)
+        {
+            let column = match table {
+                Some(table) => format!("{table}.{name}"),
+                None => name.to_string(),
+            };
+            let message = format!(
+                "column \"{column}\" must appear in GROUP BY clause \
+                 or be used in aggregate function"
+            );
+
+            return Err(TorusError::new(ErrorKind::Semantic, span, message));
+        }
+    }
+
+    Ok(())
+}
+
+/// Find column reference that is neither grouped nor aggregated.
+///
+/// # Parameters
+/// - `expr` - given SQL expression to check.
+/// - `keys` - given grouping expressions.
+///
+/// # Returns
+/// - `Column reference` - if such reference is found.
+/// - `None`             - otherwise.
+fn find_ungrouped_column<'a>(
+    expr: &'a Expr,
+    keys: &[&Expr],
+) -> Option<&'a Expr> {
+    if expr.is_aggregate() || keys.iter().any(|key| is_group_key(key, expr)) {
+        return None;
+    }
+
+    if let Expr::Column { .. } = expr {
+        return Some(expr);
+    }
+
+    expr.children()
+        .into_iter()
+        .find_map(|child| find_ungrouped_column(child, keys))
+}
+
+/// Check whether SQL expression is equal to grouping expression.
+///
+/// # Parameters
+/// - `key`  - given grouping expression.
+/// - `expr` - given SQL expression to check.
+///
+/// # Returns
+/// - `true`  - if expressions are equal.
+/// - `false` - otherwise.
+fn is_group_key(key: &Expr, expr: &Expr) -> bool {
+    match (key, expr) {
+        // Unqualified column matches column of any table.
+        (
+            Expr::Column { table, name },
+            Expr::Column {
+                table: other_table,
+                name: other_name,
+            },
+        ) => {
+            name == other_name
+                && (table.is_none()
+                    || other_table.is_none()
+                    || table == other_table)
+        }
+        _ => key == expr,
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::compiler::{
+        lexer::Lexer,
+        parser::{
+            Parser,
+            ast::{
+                BinaryOperator, Expr, FunctionArgs, JoinConstraint, JoinKind,
+                Literal, OrderByExpr, Query, Select, SelectItem, SetExpr,
+                SetOperator, Statement, TableRef,
+            },
+        },
+    };
+    use torussql_sdk::error::ErrorKind;
+
+    fn parse(input: &str) -> Query {
+        match Parser::new(Lexer::new(input)).parse().unwrap() {
+            Statement::Query(query) => *query,
+            statement => panic!("expected query, found {statement:?}"),
+        }
+    }
+
+    fn select(query: &Query) -> &Select {
+        match &query.body {
+            SetExpr::Select(select) => select,
+            body => panic!("expected SELECT clause, found {body:?}"),
+        }
+    }
+
+    fn column(table: Option<&str>, name: &str) -> Expr {
+        Expr::Column {
+            table: table.map(str::to_string),
+            name: name.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_select() {
+        let query = parse(
+            "SELECT DISTINCT u.*, u.id + 1 AS next, name \"Name\", 1 \
+             FROM users AS u WHERE u.id > 10 \
+             ORDER BY name, 2 DESC, id ASC NULLS FIRST LIMIT 5 OFFSET 10",
+        );
+
+        let next = Expr::BinaryOp {
+            left: Box::new(column(Some("u"), "id")),
+            op: BinaryOperator::Add,
+            right: Box::new(Expr::Literal(Literal::Integer(1))),
+        };
+        let condition = Expr::BinaryOp {
+            left: Box::new(column(Some("u"), "id")),
+            op: BinaryOperator::Greater,
+            right: Box::new(Expr::Literal(Literal::Integer(10))),
+        };
+
+        let body = Select {
+            distinct: true,
+            projection: vec![
+                SelectItem::QualifiedWildcard("u".to_string()),
+                SelectItem::Expr {
+                    expr: next,
+                    alias: Some("next".to_string()),
+                },
+                SelectItem::Expr {
+                    expr: column(None, "name"),
+                    alias: Some("Name".to_string()),
+                },
+                SelectItem::Expr {
+                    expr: Expr::Literal(Literal::Integer(1)),
+                    alias: None,
+                },
+            ],
+            from: vec![TableRef::Table {
+                name: "users".to_string(),
+                alias: Some("u".to_string()),
+            }],
+            selection: Some(condition),
+            group_by: Vec::new(),
+            having: None,
+            windows: Vec::new(),
+        };
+
+        let order_by = |expr, descending, nulls_first| OrderByExpr {
+            expr,
+            descending,
+            nulls_first,
+        };
+
+        let correct_query = Query {
+            with: None,
+            body: SetExpr::Select(Box::new(body)),
+            order_by: vec![
+                order_by(column(None, "name"), false, false),
+                order_by(Expr::Literal(Literal::Integer(2)), true, true),
+                order_by(column(None, "id"), false, true),
+            ],
+            limit: Some(5),
+            offset: Some(10),
+        };
+
+        assert_eq!(query, correct_query);
+    }
+
+    #[test]
+    fn test_select_without_from() {
+        let query = parse("SELECT *");
+
+        assert_eq!(select(&query).projection, vec![SelectItem::Wildcard]);
+        assert_eq!(select(&query).from, Vec::new());
+        assert_eq!(query.limit, None);
+    }
+
+    #[test]
+    fn test_joins() {
+        let query = parse(
+            "SELECT * FROM a \
+             JOIN b ON a.id = b.id \
+             LEFT OUTER JOIN c USING (id, name) \
+             NATURAL FULL JOIN d, \
+             e CROSS JOIN (f RIGHT JOIN g USING (id))",
+        );
+
+        let table = |name: &str| {
+            Box::new(TableRef::Table {
+                name: name.to_string(),
+                alias: None,
+            })
+        };
+        let join = |left, kind, right, constraint| {
+            Box::new(TableRef::Join {
+                left,
+                right,
+                kind,
+                constraint,
+            })
+        };
+
+        let condition = Expr::BinaryOp {
+            left: Box::new(column(Some("a"), "id")),
+            op: BinaryOperator::Equal,
+            right: Box::new(column(Some("b"), "id")),
+        };
+        let on = JoinConstraint::On(condition);
+        let using = |columns: &[&str]| {
+            JoinConstraint::Using(
+                columns.iter().map(|column| column.to_string()).collect(),
+            )
+        };
+
+        // ((a JOIN b) LEFT JOIN c) NATURAL FULL JOIN d
+        let first = join(
+            join(
+                join(table("a"), JoinKind::Inner, table("b"), on),
+                JoinKind::Left,
+                table("c"),
+                using(&["id", "name"]),
+            ),
+            JoinKind::Full,
+            table("d"),
+            JoinConstraint::Natural,
+        );
+
+        // e CROSS JOIN (f RIGHT JOIN g)
+        let second = join(
+            table("e"),
+            JoinKind::Cross,
+            join(table("f"), JoinKind::Right, table("g"), using(&["id"])),
+            JoinConstraint::None,
+        );
+
+        assert_eq!(select(&query).from, vec![*first, *second]);
+    }
+
+    #[test]
+    fn test_derived_table() {
+        let query = parse("SELECT x.a FROM (SELECT a FROM t LIMIT 1) AS x");
+
+        let TableRef::Derived { query, alias } = &select(&query).from[0] else {
+            panic!("expected derived table");
+        };
+
+        assert_eq!(alias, "x");
+        assert_eq!(query.limit, Some(1));
+    }
+
+    #[test]
+    fn test_group_by() {
+        let query = parse(
+            "SELECT t.dept, lower(name), COUNT(*), COUNT(DISTINCT id), \
+             SUM(salary) FILTER (WHERE active) + MAX(bonus) \
+             FROM t GROUP BY dept, 2 HAVING AVG(age) > 30 AND dept <> 'x'",
+        );
+
+        let aggregate = |name: &str, args, distinct, filter| Expr::Function {
+            name: name.to_string(),
+            args,
+            distinct,
+            filter,
+            over: None,
+        };
+
+        let list = |args| FunctionArgs::List(args);
+        let count_distinct =
+            aggregate("count", list(vec![column(None, "id")]), true, None);
+        let sum = aggregate(
+            "sum",
+            list(vec![column(None, "salary")]),
+            false,
+            Some(Box::new(column(None, "active"))),
+        );
+
+        assert_eq!(
+            select(&query).group_by,
+            vec![column(None, "dept"), Expr::Literal(Literal::Integer(2))]
+        );
+        assert!(select(&query).having.is_some());
+
+        let SelectItem::Expr { expr, .. } = &select(&query).projection[2]
+        else {
+            panic!("expected expression");
+        };
+
+        assert_eq!(
+            *expr,
+            aggregate("count", FunctionArgs::Wildcard, false, None)
+        );
+
+        let SelectItem::Expr { expr, .. } = &select(&query).projection[3]
+        else {
+            panic!("expected expression");
+        };
+
+        assert_eq!(*expr, count_distinct);
+
+        let SelectItem::Expr {
+            expr: Expr::BinaryOp { left, .. },
+            ..
+        } = &select(&query).projection[4]
+        else {
+            panic!("expected binary operation");
+        };
+
+        assert_eq!(**left, sum);
+    }
+
+    #[test]
+    fn test_invalid_grouping() {
+        let inputs = [
+            "SELECT a, COUNT(*) FROM t",
+            "SELECT a, b FROM t GROUP BY a",
+            "SELECT a + 1 FROM t GROUP BY b",
+            "SELECT * FROM t GROUP BY a",
+            "SELECT a FROM t GROUP BY a HAVING b > 1",
+            "SELECT a FROM t GROUP BY 2",
+            "SELECT a FROM t GROUP BY 0",
+            "SELECT a FROM t GROUP BY COUNT(a)",
+            "SELECT count(*) FROM t GROUP BY 1",
+            "SELECT a, rank() OVER () FROM t GROUP BY a, 2",
+            "SELECT a FROM t WHERE SUM(a) > 1",
+            "SELECT SUM(*) FROM t",
+            "SELECT lower(DISTINCT a) FROM t",
+            "SELECT lower(a) FILTER (WHERE a) FROM t",
+            "SELECT sum(sum(a)) FROM t",
+            "SELECT count(1 + max(a)) FROM t GROUP BY b",
+            "SELECT SUM(a) FILTER (WHERE sum(b) > 1) FROM t",
+        ];
+
+        for input in inputs {
+            let mut parser = Parser::new(Lexer::new(input));
+            let error = parser.parse().unwrap_err();
+
+            assert_eq!(error.kind, ErrorKind::Semantic, "{input}: {error}");
+        }
+
+        // Grouped expressions & columns of grouped tables are allowed.
+        let inputs = [
+            "SELECT a + 1, COUNT(*) FROM t GROUP BY a + 1",
+            "SELECT t.a, SUM(b) FROM t GROUP BY a",
+            "SELECT a * 2 FROM t GROUP BY 1",
+            "SELECT COUNT(*) FROM t HAVING COUNT(*) > 1",
+            // Window function may aggregate groups.
+            "SELECT SUM(SUM(a)) OVER () FROM t GROUP BY b",
+        ];
+
+        for input in inputs {
+            let mut parser = Parser::new(Lexer::new(input));
+            assert!(parser.parse().is_ok(), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let query = parse(
+            "SELECT a FROM t UNION ALL SELECT b FROM u INTERSECT SELECT c \
+             FROM v EXCEPT (SELECT d FROM w LIMIT 1) ORDER BY 1 LIMIT 10",
+        );
+
+        let SetExpr::SetOperation {
+            op: SetOperator::Except,
+            all: false,
+            left,
+            right,
+        } = &query.body
+        else {
+            panic!("expected EXCEPT, found {:?}", query.body);
+        };
+
+        let SetExpr::SetOperation {
+            op: SetOperator::Union,
+            all: true,
+            left: first,
+            right: intersection,
+        } = left.as_ref()
+        else {
+            panic!("expected UNION ALL, found {left:?}");
+        };
+
+        assert!(matches!(first.as_ref(), SetExpr::Select(_)));
+        assert!(matches!(
+            intersection.as_ref(),
+            SetExpr::SetOperation {
+                op: SetOperator::Intersect,
+                all: false,
+                ..
+            }
+        ));
+
+        let SetExpr::Query(last) = right.as_ref() else {
+            panic!("expected parenthesized query, found {right:?}");
+        };
+
+        // Trailing clauses apply to the whole combination.
+        assert_eq!(last.limit, Some(1));
+        assert_eq!(query.order_by.len(), 1);
+        assert_eq!(query.limit, Some(10));
+
+        let mut parser = Parser::new(Lexer::new("SELECT a UNION SELECT a, b"));
+        let error = parser.parse().unwrap_err();
+
+        assert_eq!(error.kind, ErrorKind::Semantic);
+    }
+
+    #[test]
+    fn test_with() {
+        let query = parse(
+            "WITH RECURSIVE subordinates (id, manager) AS ( \
+                 SELECT id, manager FROM staff WHERE id = 1 \
+                 UNION ALL \
+                 SELECT s.id, s.manager FROM staff AS s \
+                 JOIN subordinates AS t ON s.manager = t.id \
+             ), top AS (SELECT 1) \
+             SELECT * FROM subordinates",
+        );
+
+        let with = query.with.as_ref().unwrap();
+        let names: Vec<_> = with.ctes.iter().map(|cte| &cte.name).collect();
+
+        assert!(with.recursive);
+        assert_eq!(names, ["subordinates", "top"]);
+        assert_eq!(with.ctes[0].columns, ["id", "manager"]);
+        assert!(with.ctes[1].columns.is_empty());
+        assert!(matches!(
+            with.ctes[0].query.body,
+            SetExpr::SetOperation {
+                op: SetOperator::Union,
+                all: true,
+                ..
+            }
+        ));
+        assert_eq!(select(&query).projection, vec![SelectItem::Wildcard]);
+
+        // WITH clause is a part of subqueries too.
+        let query = parse("SELECT * FROM (WITH a AS (SELECT 1) SELECT 2) AS b");
+
+        let TableRef::Derived { query, .. } = &select(&query).from[0] else {
+            panic!("expected derived table");
+        };
+
+        assert!(!query.with.as_ref().unwrap().recursive);
+
+        let inputs = [
+            ("WITH SELECT 1", ErrorKind::Syntax),
+            ("WITH a SELECT 1", ErrorKind::Syntax),
+            ("WITH a AS SELECT 1", ErrorKind::Syntax),
+            ("WITH a AS (SELECT 1),", ErrorKind::Syntax),
+            ("WITH a AS (SELECT 1), a AS (SELECT 2)", ErrorKind::Semantic),
+            ("WITH a (x, y) AS (SELECT 1) SELECT 2", ErrorKind::Semantic),
+        ];
+
+        for (input, kind) in inputs {
+            let mut parser = Parser::new(Lexer::new(input));
+            let error = parser.parse().unwrap_err();
+
+            assert_eq!(error.kind, kind, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_invalid_select() {
+        let inputs = [
+            "SELECT",
+            "SELECT a,",
+            "SELECT a FROM",
+            "SELECT t. FROM t",
+            "SELECT a AS FROM t",
+            "SELECT a FROM t ORDER a",
+            "SELECT a FROM t ORDER BY a NULLS",
+            "SELECT a FROM t LIMIT a",
+            "SELECT a FROM t LIMIT -1",
+            "SELECT a FROM t OFFSET 1 LIMIT 1",
+            "SELECT a FROM t JOIN u",
+            "SELECT a FROM t LEFT u ON TRUE",
+            "SELECT a FROM t NATURAL CROSS JOIN u",
+            "SELECT a FROM t NATURAL u",
+            "SELECT a FROM t CROSS JOIN u ON TRUE",
+            "SELECT a FROM (SELECT a FROM t)",
+            "SELECT a FROM (t JOIN u USING (a)",
+            "SELECT a UNION",
+            "SELECT a UNION ALL ALL SELECT b",
+            "SELECT a ORDER BY a UNION SELECT b",
+            "(SELECT a",
+        ];
+
+        for input in inputs {
+            let mut parser = Parser::new(Lexer::new(input));
+            let error = parser.parse_script().unwrap_err().error;
+
+            assert_eq!(error.kind, ErrorKind::Syntax, "{input}");
+        }
+    }
+}
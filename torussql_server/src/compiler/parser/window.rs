@@ -0,0 +1,501 @@
+// Project name: TorusSQL.
+// Description: Relational database management system.
+// Licence: GPL-3.0.
+// Author: Alexander (@alkuzin).
+
+//! SQL window functions parsing declarations.
+
+use crate::compiler::{
+    lexer::token::{Keyword, Token},
+    parser::{
+        Parser,
+        ast::{
+            Expr, FrameBound, FrameUnits, NamedWindow, Query, Select,
+            SelectItem, SetExpr, WindowFrame, WindowSpec, WindowType,
+        },
+        is_name,
+    },
+};
+use torussql_sdk::error::{ErrorKind, Result, Span, TorusError};
+
+impl Parser<'_> {
+    /// Parse optional `OVER name` or `OVER (spec)` clause.
+    ///
+    /// # Returns
+    /// - `Window` - if clause is present.
+    /// - `None`   - otherwise.
+    /// - `Err`    - in case of failure.
+    pub(super) fn parse_over(&mut self) -> Result<Option<WindowType>> {
+        if !self.consume_keyword(Keyword::Over)? {
+            return Ok(None);
+        }
+
+        let window = match self.current_token()? {
+            Token::LeftParen => WindowType::Spec(self.parse_window_spec()?),
+            _ => WindowType::Named(self.parse_identifier("window name")?),
+        };
+
+        Ok(Some(window))
+    }
+
+    /// Parse optional `WINDOW name AS (spec), ...` clause.
+    ///
+    /// # Returns
+    /// - Window definitions - in case of success.
+    /// - `Err`              - in case of failure.
+    pub(super) fn parse_window_clause(&mut self) -> Result<Vec<NamedWindow>> {
+        if !self.consume_keyword(Keyword::Window)? {
+            return Ok(Vec::new());
+        }
+
+        let mut windows: Vec<NamedWindow> = Vec::new();
+
+        loop {
+            let span = self.current_span()?;
+            let name = self.parse_identifier("window name")?;
+
+            self.expect_keyword(Keyword::As)?;
+            let spec = self.parse_window_spec()?;
+
+            // Window can only extend windows defined before it.
+            let message = if windows.iter().any(|window| window.name == name) {
+                Some(format!("window \"{name}\" is already defined"))
+            } else if let Some(base) = &spec.base
+                && !windows.iter().any(|window| window.name == *base)
+            {
+                Some(format!("window \"{base}\" does not exist"))
+            } else {
+                None
+            };
+
+            if let Some(message) = message {
+                let kind = ErrorKind::Semantic;
+                return Err(TorusError::new(kind, span, message));
+            }
+
+            windows.push(NamedWindow { name, spec });
+
+            if !self.consume_token(&Token::Comma)? {
+                break;
+            }
+        }
+
+        Ok(windows)
+    }
+
+    /// Parse `([base] [PARTITION BY expr, ...] [ORDER BY ...] [frame])`.
+    ///
+    /// # Returns
+    /// - `Window definition` - in case of success.
+    /// - `Err`               - in case of failure.
+    fn parse_window_spec(&mut self) -> Result<WindowSpec> {
+        let span = self.current_span()?;
+        self.expect_token(&Token::LeftParen)?;
+
+        let base = match self.current_token()? {
+            Token::Keyword(
+                Keyword::Partition
+                | Keyword::Rows
+                | Keyword::Range
+                | Keyword::Groups,
+            ) => None,
+            token if is_name(token) => {
+                Some(self.parse_identifier("window name")?)
+            }
+            _ => None,
+        };
+
+        let partition_by = match self.consume_keyword(Keyword::Partition)? {
+            true => {
+                self.expect_keyword(Keyword::By)?;
+                self.parse_comma_separated(Self::parse_expr)?
+            }
+            false => Vec::new(),
+        };
+
+        let order_by = self.parse_order_by()?;
+
+        let nested_window = partition_by
+            .iter()
+            .chain(order_by.iter().map(|item| &item.expr))
+            .any(Expr::contains_window);
+
+        if nested_window {
+            let message = "window functions are not allowed in window \
+                           definitions";
+            return Err(TorusError::new(ErrorKind::Semantic, span, message));
+        }
+
+        let frame = self.parse_window_frame(order_by.len())?;
+
+        self.expect_token(&Token::RightParen)?;
+
+        Ok(WindowSpec {
+            base,
+            partition_by,
+            order_by,
+            frame,
+        })
+    }
+
+    /// Parse optional `ROWS|RANGE|GROUPS [BETWEEN start AND end | start]`.
+    ///
+    /// # Parameters
+    /// - `order_by` - given number of window ORDER BY items.
+    ///
+    /// # Returns
+    /// - `Window frame` - if frame is specified.
+    /// - `None`         - otherwise.
+    /// - `Err`          - in case of failure.
+    fn parse_window_frame(
+        &mut self,
+        order_by: usize,
+    ) -> Result<Option<WindowFrame>> {
+        let units = match self.current_token()? {
+            Token::Keyword(Keyword::Rows) => FrameUnits::Rows,
+            Token::Keyword(Keyword::Range) => FrameUnits::Range,
+            Token::Keyword(Keyword::Groups) => FrameUnits::Groups,
+            _ => return Ok(None),
+        };
+
+        let span = self.current_span()?;
+        self.next_token();
+
+        let (start, end) = match self.consume_keyword(Keyword::Between)? {
+            true => {
+                let start = self.parse_frame_bound()?;
+                self.expect_keyword(Keyword::And)?;
+
+                (start, self.parse_frame_bound()?)
+            }
+            false => (self.parse_frame_bound()?, FrameBound::CurrentRow),
+        };
+
+        let has_offset = [&start, &end].into_iter().any(|bound| {
+            matches!(bound, FrameBound::Preceding(_) | FrameBound::Following(_))
+        });
+
+        let message = if start == FrameBound::UnboundedFollowing {
+            "frame start cannot be UNBOUNDED FOLLOWING"
+        } else if end == FrameBound::UnboundedPreceding {
+            "frame end cannot be UNBOUNDED PRECEDING"
+        } else if bound_position(&start) > bound_position(&end) {
+            "frame start cannot be after frame end"
+        } else if units == FrameUnits::Groups && order_by == 0 {
+            "GROUPS mode requires an ORDER BY clause"
+        } else if units == FrameUnits::Range && has_offset && order_by != 1 {
+            "RANGE with offset requires exactly one ORDER BY column"
+        } else {
+            return Ok(Some(WindowFrame { units, start, end }));
+        };
+
+        Err(TorusError::new(ErrorKind::Semantic, span, message))
+    }
+
+    /// Parse window frame bound.
+    ///
+    /// # Returns
+    /// - `Frame bound` - in case of success.
+    /// - `Err`         - in case of failure.
+    fn parse_frame_bound(&mut self) -> Result<FrameBound> {
+        if self.consume_keyword(Keyword::Current)? {
+            self.expect_keyword(Keyword::Row)?;
+            return Ok(FrameBound::CurrentRow);
+        }
+
+        let offset = match self.consume_keyword(Keyword::Unbounded)? {
+            true => None,
+            false => Some(Box::new(self.parse_expr()?)),
+        };
+
+        let preceding = match self.current_token()? {
+            Token::Keyword(Keyword::Preceding) => true,
+            Token::Keyword(Keyword::Following) => false,
+            _ => return Err(self.unexpected("PRECEDING or FOLLOWING")),
+        };
+
+        self.next_token();
+
+        let bound = match (offset, preceding) {
+            (None, true) => FrameBound::UnboundedPreceding,
+            (None, false) => FrameBound::UnboundedFollowing,
+            (Some(offset), true) => FrameBound::Preceding(offset),
+            (Some(offset), false) => FrameBound::Following(offset),
+        };
+
+        Ok(bound)
+    }
+}
+
+/// Get position of frame bound relative to current row.
+///
+/// # Parameters
+/// - `bound` - given window frame bound.
+///
+/// # Returns
+/// - Position in order of rows of partition.
+fn bound_position(bound: &FrameBound) -> u8 {
+    match bound {
+        FrameBound::UnboundedPreceding => 0,
+        FrameBound::Preceding(_) => 1,
+        FrameBound::CurrentRow => 2,
+        FrameBound::Following(_) => 3,
+        FrameBound::UnboundedFollowing => 4,
+    }
+}
+
+/// Check that window functions of SELECT clause refer to defined windows.
+///
+/// # Parameters
+/// - `select` - given SELECT clause.
+/// - `spans`  - given positions of SELECT items.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - otherwise.
+pub(super) fn check_window_refs(select: &Select, spans: &[Span]) -> Result<()> {
+    let exprs = select
+        .projection
+        .iter()
+        .zip(spans.iter().copied())
+        .filter_map(|(item, span)| match item {
+            SelectItem::Expr { expr, .. } => Some((expr, span)),
+            _ => None,
+        });
+
+    check_expr_window_refs(exprs, &select.windows)
+}
+
+/// Check that window functions of ORDER BY clause refer to defined windows.
+///
+/// # Parameters
+/// - `query` - given SQL query.
+/// - `span`  - given ORDER BY clause position.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - otherwise.
+pub(super) fn check_order_window_refs(query: &Query, span: Span) -> Result<()> {
+    // Only windows of plain SELECT body are visible to ORDER BY.
+    let windows = match &query.body {
+        SetExpr::Select(select) => select.windows.as_slice(),
+        _ => &[],
+    };
+    let exprs = query.order_by.iter().map(|item| (&item.expr, span));
+
+    check_expr_window_refs(exprs, windows)
+}
+
+/// Check that window functions of expressions refer to defined windows.
+///
+/// # Parameters
+/// - `exprs`   - given SQL expressions with their positions.
+/// - `windows` - given window definitions.
+///
+/// # Returns
+/// - `Ok`  - in case of success.
+/// - `Err` - otherwise.
+fn check_expr_window_refs<'a>(
+    exprs: impl IntoIterator<Item = (&'a Expr, Span)>,
+    windows: &[NamedWindow],
+) -> Result<()> {
+    for (expr, span) in exprs {
+        if let Some(name) = find_undefined_window(expr, windows) {
+            let message = format!("window \"{name}\" does not exist");
+            return Err(TorusError::new(ErrorKind::Semantic, span, message));
+        }
+    }
+
+    Ok(())
+}
+
+/// Find name of window that is referred but not defined.
+///
+/// # Parameters
+/// - `expr`    - given SQL expression to check.
+/// - `windows` - given window definitions.
+///
+/// # Returns
+/// - `Window name` - if such reference is found.
+/// - `None`        - otherwise.
+fn find_undefined_window<'a>(
+    expr: &'a Expr,
+    windows: &[NamedWindow],
+) -> Option<&'a str> {
+    let name = match expr {
+        Expr::Function {
+            over: Some(WindowType::Named(name)),
+            ..
+        } => Some(name),
+        Expr::Function {
+            over: Some(WindowType::Spec(spec)),
+            ..
+        } => spec.base.as_ref(),
+        _ => None,
+    };
+
+    if let Some(name) = name
+        && !windows.iter().any(|window| window.name == *name)
+    {
+        return Some(name);
+    }
+
+    expr.children()
+        .into_iter()
+        .find_map(|child| find_undefined_window(child, windows))
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::compiler::{
+        lexer::Lexer,
+        parser::{
+            Parser,
+            ast::{
+                Expr, FrameBound, FrameUnits, Literal, Select, SelectItem,
+                SetExpr, Statement, WindowFrame, WindowType,
+            },
+        },
+    };
+    use torussql_sdk::error::ErrorKind;
+
+    fn parse(input: &str) -> Select {
+        let statement = Parser::new(Lexer::new(input)).parse().unwrap();
+
+        let Statement::Query(query) = statement else {
+            panic!("expected query, found {statement:?}");
+        };
+
+        match query.body {
+            SetExpr::Select(select) => *select,
+            body => panic!("expected SELECT clause, found {body:?}"),
+        }
+    }
+
+    fn over(item: &SelectItem) -> &WindowType {
+        match item {
+            SelectItem::Expr {
+                expr:
+                    Expr::Function {
+                        over: Some(over), ..
+                    },
+                ..
+            } => over,
+            _ => panic!("expected window function, found {item:?}"),
+        }
+    }
+
+    #[test]
+    fn test_window_functions() {
+        let select = parse(
+            "SELECT row_number() OVER (PARTITION BY d ORDER BY s DESC), \
+                    sum(s) OVER w, \
+                    lag(s, 1) OVER (w ORDER BY s RANGE 5 PRECEDING), \
+                    first_value(s) OVER (w ROWS BETWEEN UNBOUNDED PRECEDING \
+                                         AND 1 FOLLOWING) \
+             FROM t WINDOW w AS (PARTITION BY d)",
+        );
+
+        let WindowType::Spec(spec) = over(&select.projection[0]) else {
+            panic!("expected inline window");
+        };
+
+        assert_eq!(spec.base, None);
+        assert_eq!(spec.partition_by.len(), 1);
+        assert!(spec.order_by[0].descending);
+        assert_eq!(spec.frame, None);
+
+        assert_eq!(
+            over(&select.projection[1]),
+            &WindowType::Named("w".to_string())
+        );
+
+        let WindowType::Spec(spec) = over(&select.projection[2]) else {
+            panic!("expected inline window");
+        };
+        let five = Box::new(Expr::Literal(Literal::Integer(5)));
+
+        assert_eq!(spec.base.as_deref(), Some("w"));
+        assert_eq!(
+            spec.frame,
+            Some(WindowFrame {
+                units: FrameUnits::Range,
+                start: FrameBound::Preceding(five),
+                end: FrameBound::CurrentRow,
+            })
+        );
+
+        let WindowType::Spec(spec) = over(&select.projection[3]) else {
+            panic!("expected inline window");
+        };
+        let one = Box::new(Expr::Literal(Literal::Integer(1)));
+
+        assert_eq!(
+            spec.frame,
+            Some(WindowFrame {
+                units: FrameUnits::Rows,
+                start: FrameBound::UnboundedPreceding,
+                end: FrameBound::Following(one),
+            })
+        );
+
+        assert_eq!(select.windows.len(), 1);
+        assert_eq!(select.windows[0].name, "w");
+
+        // Aggregates with OVER clause don't group rows.
+        parse("SELECT a, count(*) OVER () FROM t");
+        parse("SELECT a, sum(sum(b)) OVER (ORDER BY a) FROM t GROUP BY a");
+        parse("SELECT a FROM t WINDOW w AS () ORDER BY sum(a) OVER w");
+    }
+
+    #[test]
+    fn test_invalid_window_functions() {
+        let inputs = [
+            "SELECT rank() FROM t",
+            "SELECT lower(a) OVER () FROM t",
+            "SELECT count(DISTINCT a) OVER () FROM t",
+            "SELECT sum(a) OVER w FROM t",
+            "SELECT sum(a) OVER (v) FROM t WINDOW w AS ()",
+            "SELECT a FROM t WINDOW w AS (), w AS ()",
+            "SELECT a FROM t WINDOW w AS (v), v AS ()",
+            "SELECT sum(a) OVER (ROWS UNBOUNDED FOLLOWING) FROM t",
+            "SELECT sum(a) OVER (ROWS BETWEEN CURRENT ROW AND 1 PRECEDING)",
+            "SELECT sum(a) OVER (GROUPS 1 PRECEDING) FROM t",
+            "SELECT sum(a) OVER (RANGE 1 PRECEDING) FROM t",
+            "SELECT a FROM t WHERE rank() OVER () = 1",
+            "SELECT a FROM t GROUP BY rank() OVER ()",
+            "SELECT a FROM t GROUP BY a HAVING sum(a) OVER () > 1",
+            "SELECT sum(rank() OVER ()) FROM t",
+            "SELECT count(*) FILTER (WHERE rank() OVER () > 1) FROM t",
+            "SELECT lag(rank() OVER ()) OVER () FROM t",
+            "SELECT sum(a) OVER (ORDER BY rank() OVER ()) FROM t",
+            "SELECT a FROM t WINDOW w AS (PARTITION BY sum(a) OVER ())",
+            "SELECT a FROM t ORDER BY sum(a) OVER w",
+            "SELECT a FROM t WINDOW w AS () ORDER BY sum(a) OVER (v)",
+            "SELECT a FROM t UNION SELECT a FROM u ORDER BY sum(a) OVER w",
+        ];
+
+        for input in inputs {
+            let mut parser = Parser::new(Lexer::new(input));
+            let error = parser.parse().unwrap_err();
+
+            assert_eq!(error.kind, ErrorKind::Semantic, "{input}: {error}");
+        }
+
+        let inputs = [
+            "SELECT sum(a) OVER FROM t",
+            "SELECT sum(a) OVER (ROWS 1) FROM t",
+            "SELECT sum(a) OVER (ROWS CURRENT) FROM t",
+            "SELECT sum(a) OVER (ROWS BETWEEN 1 PRECEDING) FROM t",
+            "SELECT sum(a) OVER (PARTITION d) FROM t",
+            "SELECT a FROM t WINDOW w (ORDER BY a)",
+        ];
+
+        for input in inputs {
+            let mut parser = Parser::new(Lexer::new(input));
+            let error = parser.parse_script().unwrap_err().error;
+
+            assert_eq!(error.kind, ErrorKind::Syntax, "{input}: {error}");
+        }
+    }
+}
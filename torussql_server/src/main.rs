@@ -5,6 +5,8 @@
 
 //! TorusSQL server entry point.
 
+// TODO: remove after integrating compiler into server.
+#[allow(dead_code)]
 pub mod compiler;
 
 use torussql_sdk::log;